@@ -0,0 +1,174 @@
+use std::{collections::HashMap, fmt, fs, path::Path};
+
+use crate::{Error, Result};
+
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+/// Known answers to check solutions against, read from a TOML file with one table per day:
+///
+/// ```toml
+/// [day01]
+/// part1 = 24000
+/// part2 = "45000"
+/// ```
+///
+/// Any TOML string or integer can be an answer, so multi-line answers can be written as `"""`
+/// strings. Only `[dayNN]` tables with `part1`/`part2` keys are allowed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers(HashMap<(usize, usize), String>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// There is no known answer for this part.
+    Unknown,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "fail",
+            Status::Unknown => "unknown",
+        };
+        f.pad(status)
+    }
+}
+
+fn day(table: &str) -> Result<usize> {
+    table
+        .strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .ok_or_else(|| {
+            Error::InvalidAnswers(format!("expected a [dayNN] table, found [{}]", table))
+        })
+}
+
+fn part(key: &str) -> Result<usize> {
+    match key {
+        "part1" => Ok(1),
+        "part2" => Ok(2),
+        _ => Err(Error::InvalidAnswers(format!(
+            "expected part1 or part2, found {}",
+            key
+        ))),
+    }
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self> {
+        let tables: HashMap<String, HashMap<String, toml::Value>> =
+            toml::from_str(input).map_err(|e| Error::InvalidAnswers(e.to_string()))?;
+        let mut answers = HashMap::new();
+
+        for (table, parts) in tables {
+            let day = day(&table)?;
+            for (key, value) in parts {
+                let part = part(&key)?;
+                let answer = match value {
+                    toml::Value::String(answer) => answer,
+                    toml::Value::Integer(answer) => answer.to_string(),
+                    value => {
+                        return Err(Error::InvalidAnswers(format!(
+                            "expected a string or an integer for {}.{}, found {}",
+                            table,
+                            key,
+                            value.type_str()
+                        )))
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Answers(answers))
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let input = fs::read_to_string(path).map_err(|error| Error::MissingInput {
+            input: path.display().to_string(),
+            error,
+        })?;
+        Self::parse(&input)
+    }
+
+    pub fn get(&self, day: usize, part: usize) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Trailing whitespace is ignored on both sides, as it is easily lost when editing the file.
+    pub fn check(&self, day: usize, part: usize, answer: &str) -> Status {
+        match self.get(day, part) {
+            None => Status::Unknown,
+            Some(expected) if expected.trim_end() == answer.trim_end() => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const ANSWERS: &str = r###"# Sample answers
+[day01]
+part1 = 24_000
+part2 = '45000' # as a literal string
+
+[day10]
+part2 = "##..\n.##."
+
+[day11]
+part1 = """
+##..
+.##.
+"""
+"###;
+
+    #[test]
+    fn check_answers() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(Status::Pass, answers.check(1, 1, "24000"));
+        assert_eq!(Status::Pass, answers.check(1, 2, "45000"));
+        assert_eq!(Status::Pass, answers.check(10, 2, "##..\n.##.\n"));
+        assert_eq!(Status::Pass, answers.check(11, 1, "##..\n.##."));
+        assert_eq!(
+            Status::Fail {
+                expected: "24000".to_string()
+            },
+            answers.check(1, 1, "24001")
+        );
+        assert_eq!(Status::Unknown, answers.check(10, 1, "13140"));
+    }
+
+    #[test]
+    fn answers_have_to_be_in_day_tables() {
+        let error = Answers::parse("[day01]\npart3 = 1\n").unwrap_err();
+        assert_eq!(
+            "invalid answers: expected part1 or part2, found part3",
+            error.to_string()
+        );
+
+        let error = Answers::parse("[day01]\npart1 = [1]\n").unwrap_err();
+        assert_eq!(
+            "invalid answers: expected a string or an integer for day01.part1, found array",
+            error.to_string()
+        );
+
+        assert!(matches!(
+            Answers::parse("part1 = 1\n"),
+            Err(Error::InvalidAnswers(_))
+        ));
+        assert!(matches!(
+            Answers::parse("[day01\n"),
+            Err(Error::InvalidAnswers(_))
+        ));
+    }
+}
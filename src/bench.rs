@@ -0,0 +1,155 @@
+use std::{fs, path::Path, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::days::{self, Solver, Timings};
+use crate::{Error, Result};
+
+/// How long a phase took over all timed iterations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", with = "nanos")]
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+        let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+
+        Some(Stats {
+            min: samples[0],
+            median,
+            mean,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayBench {
+    pub fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+/// The results of a benchmark run, as saved to compare later runs against.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub warmup: usize,
+    pub iterations: usize,
+    pub days: Vec<DayBench>,
+}
+
+impl Report {
+    pub fn read(path: &Path) -> Result<Self> {
+        let report_error = |error| Error::Report {
+            file: path.display().to_string(),
+            error,
+        };
+        let json = fs::read_to_string(path).map_err(|e| report_error(e.into()))?;
+        serde_json::from_str(&json).map_err(|e| report_error(e.into()))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let report_error = |error| Error::Report {
+            file: path.display().to_string(),
+            error,
+        };
+        let json = serde_json::to_string_pretty(self).map_err(|e| report_error(e.into()))?;
+        fs::write(path, json + "\n").map_err(|e| report_error(e.into()))
+    }
+
+    pub fn day(&self, day: usize) -> Option<&DayBench> {
+        self.days.iter().find(|bench| bench.day == day)
+    }
+}
+
+/// Solves a day `warmup` times without timing it, then `iterations` more times to time each
+/// phase.
+pub fn bench(
+    day: usize,
+    solve: Solver,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<DayBench> {
+    for _ in 0..warmup {
+        days::run(solve, input)?;
+    }
+
+    let timings = (0..iterations)
+        .map(|_| days::run(solve, input).map(|run| run.timings))
+        .collect::<Result<Vec<Timings>>>()?;
+    let stats = |phase: fn(&Timings) -> Duration| {
+        Stats::from_samples(timings.iter().map(phase).collect())
+            .ok_or_else(|| Error::invalid_state("benchmarking needs at least one iteration"))
+    };
+
+    Ok(DayBench {
+        day,
+        parse: stats(|t| t.parse)?,
+        part1: stats(|t| t.part1)?,
+        part2: stats(|t| t.part2)?,
+    })
+}
+
+/// Durations as a plain number of nanoseconds, which is easier to plot than serde's
+/// `{ secs, nanos }`.
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn stats_from_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+        assert_eq!(
+            Some(Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_micros(2500),
+                mean: Duration::from_micros(2500),
+            }),
+            Stats::from_samples(samples)
+        );
+        assert_eq!(None, Stats::from_samples(vec![]));
+    }
+}
@@ -1,31 +1,22 @@
-use std::collections::HashSet;
-use std::hash::Hash;
+use std::{env, process};
 
-fn has_unique_elements<T>(iter: T) -> bool
-where
-    T: IntoIterator,
-    T::Item: Eq + Hash,
-{
-    let mut uniq = HashSet::new();
-    iter.into_iter().all(move |x| uniq.insert(x))
-}
-
-fn distinct_n_char_location(signal: &[char], n: usize) -> Option<usize> {
-    for idx in 0..(signal.len() - n) {
-        let slice = &signal[idx..(idx + n)];
-        if has_unique_elements(slice) {
-            return Some(idx + n);
-        }
-    }
-    None
-}
+use aoc22::days::{day06::Day06, solve};
+use aoc22::input::InputSource;
 
 fn main() {
-    let input = include_str!("../../data/day06.txt");
-    let signal = input.chars().into_iter().collect::<Vec<char>>();
+    let source = env::args()
+        .nth(1)
+        .map(|arg| InputSource::from_arg(&arg))
+        .unwrap_or_default();
 
-    let start_of_packet_marker = distinct_n_char_location(&signal, 4);
-    println!("{:?}", start_of_packet_marker);
-    let start_of_message_marker = distinct_n_char_location(&signal, 14);
-    println!("{:?}", start_of_message_marker);
+    match source.read(6).and_then(|input| solve::<Day06>(&input)) {
+        Ok((part1, part2)) => {
+            println!("Day 06 - Part 01: {}", part1);
+            println!("Day 06 - Part 02: {}", part2);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
 }
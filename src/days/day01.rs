@@ -0,0 +1,108 @@
+use std::fmt::Display;
+
+use crate::{Error, Result, Solution};
+use itertools::Itertools;
+
+pub type Calorie = usize;
+pub type CaloriesCarriedByElf = Vec<Calorie>;
+
+pub fn parse_calories_carried_by_elves(calorie_list: &str) -> Result<Vec<CaloriesCarriedByElf>> {
+    calorie_list
+        .split("\n\n")
+        .map(|calories_carried_by_elf| {
+            calories_carried_by_elf
+                .lines()
+                .map(|calories_in_meal| {
+                    calories_in_meal
+                        .parse::<Calorie>()
+                        .map_err(|_| Error::parse(calorie_list, calories_in_meal, "calories"))
+                })
+                .collect::<Result<CaloriesCarriedByElf>>()
+        })
+        .collect()
+}
+
+pub fn part1_most_calories_carried(calorie_list: &[CaloriesCarriedByElf]) -> Calorie {
+    calorie_list
+        .iter()
+        .map(|calorie_carried_by_elf| calorie_carried_by_elf.iter().sum::<Calorie>())
+        .max()
+        .unwrap()
+}
+
+pub fn part2_most_calories_carried_by_3_elves(calorie_list: &[CaloriesCarriedByElf]) -> Calorie {
+    calorie_list
+        .iter()
+        .map(|calorie_carried_by_elf| calorie_carried_by_elf.iter().sum::<Calorie>())
+        .sorted()
+        .rev()
+        .take(3)
+        .sum::<Calorie>()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<CaloriesCarriedByElf>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_calories_carried_by_elves(input)
+    }
+
+    fn part1(calorie_list: &Self::Parsed) -> Result<impl Display> {
+        Ok(part1_most_calories_carried(calorie_list))
+    }
+
+    fn part2(calorie_list: &Self::Parsed) -> Result<impl Display> {
+        Ok(part2_most_calories_carried_by_3_elves(calorie_list))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const TEST_INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    fn input() -> Vec<CaloriesCarriedByElf> {
+        vec![
+            vec![1000, 2000, 3000],
+            vec![4000],
+            vec![5000, 6000],
+            vec![7000, 8000, 9000],
+            vec![10000],
+        ]
+    }
+
+    #[test]
+    fn parsing_works() {
+        assert_eq!(
+            input(),
+            parse_calories_carried_by_elves(TEST_INPUT).unwrap()
+        )
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(24000, part1_most_calories_carried(&input()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(45000, part2_most_calories_carried_by_3_elves(&input()));
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt::Display;
+
+use crate::{Error, Result, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+pub fn hand_value(hand: Hand) -> usize {
+    match hand {
+        Hand::Rock => 1,
+        Hand::Paper => 2,
+        Hand::Scissors => 3,
+    }
+}
+
+pub fn outcome_value(outcome: Outcome) -> usize {
+    match outcome {
+        Outcome::Win => 6,
+        Outcome::Loss => 0,
+        Outcome::Draw => 3,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Part1Round {
+    pub opponent: Hand,
+    pub player: Hand,
+}
+
+impl Part1Round {
+    pub fn score(&self) -> usize {
+        hand_value(self.player) + outcome_value(self.outcome())
+    }
+
+    pub fn outcome(&self) -> Outcome {
+        match (self.player, self.opponent) {
+            (Hand::Rock, Hand::Rock) => Outcome::Draw,
+            (Hand::Rock, Hand::Paper) => Outcome::Loss,
+            (Hand::Rock, Hand::Scissors) => Outcome::Win,
+            (Hand::Paper, Hand::Rock) => Outcome::Win,
+            (Hand::Paper, Hand::Paper) => Outcome::Draw,
+            (Hand::Paper, Hand::Scissors) => Outcome::Loss,
+            (Hand::Scissors, Hand::Rock) => Outcome::Loss,
+            (Hand::Scissors, Hand::Paper) => Outcome::Win,
+            (Hand::Scissors, Hand::Scissors) => Outcome::Draw,
+        }
+    }
+}
+
+pub fn parse_part1_input(rounds: &str) -> Result<Vec<Part1Round>> {
+    rounds
+        .lines()
+        .map(|round| parse_part1_round(rounds, round))
+        .collect()
+}
+
+fn parse_part1_round(rounds: &str, round: &str) -> Result<Part1Round> {
+    match round.split(' ').collect::<Vec<&str>>()[..] {
+        [p1, p2] => Ok(Part1Round {
+            opponent: string_to_hand(rounds, p1)?,
+            player: string_to_hand(rounds, p2)?,
+        }),
+        _ => Err(Error::parse(rounds, round, "two space separated columns")),
+    }
+}
+
+fn string_to_hand(rounds: &str, hand: &str) -> Result<Hand> {
+    match hand {
+        "A" | "X" => Ok(Hand::Rock),
+        "B" | "Y" => Ok(Hand::Paper),
+        "C" | "Z" => Ok(Hand::Scissors),
+        _ => Err(Error::parse(rounds, hand, "one of A, B, C, X, Y or Z")),
+    }
+}
+
+pub fn part1_total_score(rounds: &[Part1Round]) -> usize {
+    rounds.iter().map(|round| round.score()).sum()
+}
+
+////////////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Part2Round {
+    pub opponent: Hand,
+    pub required_outcome: Outcome,
+}
+
+pub fn parse_part2_input(rounds: &str) -> Result<Vec<Part2Round>> {
+    rounds
+        .lines()
+        .map(|round| parse_part2_round(rounds, round))
+        .collect()
+}
+
+fn parse_part2_round(rounds: &str, round: &str) -> Result<Part2Round> {
+    match round.split(' ').collect::<Vec<&str>>()[..] {
+        [p1, p2] => Ok(Part2Round {
+            opponent: string_to_hand(rounds, p1)?,
+            required_outcome: string_to_outcome(rounds, p2)?,
+        }),
+        _ => Err(Error::parse(rounds, round, "two space separated columns")),
+    }
+}
+
+fn string_to_outcome(rounds: &str, outcome: &str) -> Result<Outcome> {
+    match outcome {
+        "X" => Ok(Outcome::Loss),
+        "Y" => Ok(Outcome::Draw),
+        "Z" => Ok(Outcome::Win),
+        _ => Err(Error::parse(rounds, outcome, "one of X, Y or Z")),
+    }
+}
+
+impl Part2Round {
+    pub fn score(&self) -> usize {
+        hand_value(self.required_hand()) + outcome_value(self.required_outcome)
+    }
+
+    pub fn required_hand(&self) -> Hand {
+        match (self.opponent, self.required_outcome) {
+            (Hand::Rock, Outcome::Win) => Hand::Paper,
+            (Hand::Rock, Outcome::Loss) => Hand::Scissors,
+            (Hand::Rock, Outcome::Draw) => Hand::Rock,
+            (Hand::Paper, Outcome::Win) => Hand::Scissors,
+            (Hand::Paper, Outcome::Loss) => Hand::Rock,
+            (Hand::Paper, Outcome::Draw) => Hand::Paper,
+            (Hand::Scissors, Outcome::Win) => Hand::Rock,
+            (Hand::Scissors, Outcome::Loss) => Hand::Paper,
+            (Hand::Scissors, Outcome::Draw) => Hand::Scissors,
+        }
+    }
+}
+
+pub fn part2_total_score(rounds: &[Part2Round]) -> usize {
+    rounds.iter().map(|round| round.score()).sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = (Vec<Part1Round>, Vec<Part2Round>);
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok((parse_part1_input(input)?, parse_part2_input(input)?))
+    }
+
+    fn part1((rounds, _): &Self::Parsed) -> Result<impl Display> {
+        Ok(part1_total_score(rounds))
+    }
+
+    fn part2((_, rounds): &Self::Parsed) -> Result<impl Display> {
+        Ok(part2_total_score(rounds))
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use crate::{Error, Result, Solution};
+use itertools::Itertools;
+
+pub const GROUP_SIZE: usize = 3;
+
+pub type Rucksack = Vec<char>;
+pub type Compartment = HashSet<char>;
+pub type Item = char;
+pub type ItemPriority = usize;
+pub type Group = Vec<HashSet<char>>;
+pub type Badge = char;
+
+pub fn parse_input(input: &str) -> Result<Vec<Rucksack>> {
+    input
+        .lines()
+        .map(|rucksack| {
+            rucksack
+                .char_indices()
+                .map(|(i, item)| {
+                    if item.is_ascii_alphabetic() {
+                        Ok(item)
+                    } else {
+                        Err(Error::parse(input, &rucksack[i..], "an item a-z or A-Z"))
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Panics if `item` is not a letter, which rucksacks from [`parse_input`] never hold.
+pub fn item_priority(item: Item) -> ItemPriority {
+    if item.is_ascii_lowercase() {
+        item as usize - 'a' as usize + 1
+    } else if item.is_ascii_uppercase() {
+        item as usize - 'A' as usize + 27
+    } else {
+        unreachable!("items are checked while parsing")
+    }
+}
+
+pub fn priority_of_overlapping_item(rucksack: &Rucksack) -> ItemPriority {
+    let compartment_size = rucksack.len() / 2;
+
+    let first_compartment: Compartment = rucksack[..compartment_size].iter().copied().collect();
+    let second_compartment: Compartment = rucksack[compartment_size..].iter().copied().collect();
+
+    first_compartment
+        .intersection(&second_compartment)
+        .copied()
+        .map(item_priority)
+        .sum::<usize>()
+}
+
+pub fn identify_group_badges(rucksacks: Group) -> Vec<Badge> {
+    rucksacks
+        .into_iter()
+        .reduce(|group, rucksack| -> HashSet<_> {
+            group.intersection(&rucksack).copied().collect()
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .collect::<Vec<Badge>>()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<Rucksack>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_input(input)
+    }
+
+    fn part1(rucksacks: &Self::Parsed) -> Result<impl Display> {
+        Ok(rucksacks
+            .iter()
+            .map(priority_of_overlapping_item)
+            .sum::<usize>())
+    }
+
+    fn part2(rucksacks: &Self::Parsed) -> Result<impl Display> {
+        Ok(rucksacks
+            .iter()
+            .map(|rucksack| rucksack.iter().copied().collect::<HashSet<char>>())
+            .chunks(GROUP_SIZE)
+            .into_iter()
+            .map(|rucksacks| {
+                identify_group_badges(rucksacks.collect())
+                    .iter()
+                    .map(|item| item_priority(*item))
+                    .sum::<usize>()
+            })
+            .sum::<usize>())
+    }
+}
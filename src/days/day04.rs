@@ -0,0 +1,44 @@
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+use crate::parsers::{decimal, parse_lines, tag, IResult};
+use crate::range::{Overlap, Subsume};
+use crate::{Result, Solution};
+
+fn range(input: &str) -> IResult<&str, RangeInclusive<usize>> {
+    let (input, start) = decimal(input)?;
+    let (input, _) = tag("-")(input)?;
+    let (input, end) = decimal(input)?;
+
+    let range = start..=end;
+    Ok((input, range))
+}
+
+fn range_pair(input: &str) -> IResult<&str, (RangeInclusive<usize>, RangeInclusive<usize>)> {
+    let (input, first) = range(input)?;
+    let (input, _) = tag(",")(input)?;
+    let (input, second) = range(input)?;
+
+    Ok((input, (first, second)))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Vec<(RangeInclusive<usize>, RangeInclusive<usize>)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_lines(range_pair, input)
+    }
+
+    fn part1(ranges: &Self::Parsed) -> Result<impl Display> {
+        Ok(ranges
+            .iter()
+            .filter(|(r1, r2)| r1.subsumes(r2) || r2.subsumes(r1))
+            .count())
+    }
+
+    fn part2(ranges: &Self::Parsed) -> Result<impl Display> {
+        Ok(ranges.iter().filter(|(r1, r2)| r1.overlaps(r2)).count())
+    }
+}
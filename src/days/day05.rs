@@ -0,0 +1,199 @@
+use crate::parsers::{context, decimal, parse_all, separated_all, tag, IResult};
+use crate::{math::transpose, Error, Result, Solution};
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    character::complete::{anychar, one_of},
+    combinator::map,
+    multi::{many1, separated_list1},
+    sequence::{delimited, separated_pair},
+};
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub type Crate = String;
+pub type CrateStack = Vec<Crate>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Command {
+    pub crates: usize,
+    pub from_stack: usize,
+    pub to_stack: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Ship {
+    stacks: Vec<CrateStack>,
+    crate_mover: CrateMover,
+}
+
+/// The crane moving the crates: a 9000 moves them one at a time, a 9001 all at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrateMover {
+    CM9000,
+    CM9001,
+}
+
+impl Ship {
+    pub fn new(stacks: Vec<CrateStack>, crate_mover: CrateMover) -> Self {
+        Ship {
+            stacks,
+            crate_mover,
+        }
+    }
+
+    pub fn stacks(&self) -> &[CrateStack] {
+        &self.stacks
+    }
+
+    fn stack_index(&self, stack: usize) -> Result<usize> {
+        if (1..=self.stacks.len()).contains(&stack) {
+            Ok(stack - 1)
+        } else {
+            Err(Error::invalid_state(format!("there is no stack {}", stack)))
+        }
+    }
+
+    pub fn move_crates(&mut self, command: &Command) -> Result<()> {
+        let from = self.stack_index(command.from_stack)?;
+        let to = self.stack_index(command.to_stack)?;
+
+        if self.stacks[from].len() < command.crates {
+            return Err(Error::invalid_state(format!(
+                "can't move {} crates off stack {}, which only has {}",
+                command.crates,
+                command.from_stack,
+                self.stacks[from].len()
+            )));
+        }
+
+        match self.crate_mover {
+            CrateMover::CM9000 => {
+                for _ in 0..command.crates {
+                    let crat = self.stacks[from].pop();
+                    self.stacks[to].extend(crat);
+                }
+            }
+            CrateMover::CM9001 => {
+                let final_length = self.stacks[from].len() - command.crates;
+                let mut crates = self.stacks[from].split_off(final_length);
+                self.stacks[to].append(&mut crates);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get_top_crates(&self) -> Result<Vec<&Crate>> {
+        self.stacks
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                v.last()
+                    .ok_or_else(|| Error::invalid_state(format!("stack {} is empty", i + 1)))
+            })
+            .collect()
+    }
+}
+
+fn parse_command(input: &str) -> IResult<&str, Command> {
+    let (input, _) = tag("move ")(input)?;
+    let (input, n) = decimal(input)?;
+    let (input, _) = tag(" from ")(input)?;
+    let (input, f) = decimal(input)?;
+    let (input, _) = tag(" to ")(input)?;
+    let (input, t) = decimal(input)?;
+
+    let command = Command {
+        crates: n,
+        from_stack: f,
+        to_stack: t,
+    };
+
+    Ok((input, command))
+}
+
+impl FromStr for Command {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_all(parse_command, s)
+    }
+}
+
+fn crat(input: &str) -> IResult<&str, char> {
+    delimited(tag("["), anychar, tag("]"))(input)
+}
+
+fn optional_crat(input: &str) -> IResult<&str, char> {
+    let empty_crate = map(tag("   "), |_| ' ');
+    alt((empty_crate, crat))(input)
+}
+
+fn stack_frame(input: &str) -> IResult<&str, Vec<char>> {
+    separated_list1(tag(" "), optional_crat)(input)
+}
+
+fn stacks(input: &str) -> IResult<&str, Vec<CrateStack>> {
+    let (input, stacks) = separated_list1(tag("\n"), stack_frame)(input)?;
+    let (input, _) = tag("\n")(input)?;
+    let (input, _) = many1(one_of("123456789 "))(input)?;
+
+    // Editors like to strip trailing whitespace, which leaves the rows ragged
+    let width = stacks.iter().map(Vec::len).max().unwrap_or_default();
+    let stacks = stacks
+        .into_iter()
+        .map(|mut frame| {
+            frame.resize(width, ' ');
+            frame
+        })
+        .collect();
+
+    let result = transpose(stacks)
+        .into_iter()
+        .map(|line| {
+            line.iter()
+                .rev()
+                .filter(|c| **c != ' ')
+                .map(|c| c.to_string())
+                .collect::<CrateStack>()
+        })
+        .collect::<Vec<CrateStack>>();
+
+    Ok((input, result))
+}
+
+fn manifest(input: &str) -> IResult<&str, (Vec<CrateStack>, Vec<Command>)> {
+    separated_pair(
+        context("crate stacks", stacks),
+        tag("\n\n"),
+        separated_all(tag("\n"), context("move command", parse_command)),
+    )(input)
+}
+
+pub fn perform_commands(ship: &mut Ship, commands: &[Command]) -> Result<Crate> {
+    for command in commands {
+        ship.move_crates(command)?
+    }
+    Ok(ship.get_top_crates()?.iter().copied().join(""))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = (Vec<CrateStack>, Vec<Command>);
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_all(manifest, input)
+    }
+
+    fn part1((stacks, commands): &Self::Parsed) -> Result<impl Display> {
+        let mut ship = Ship::new(stacks.clone(), CrateMover::CM9000);
+        perform_commands(&mut ship, commands)
+    }
+
+    fn part2((stacks, commands): &Self::Parsed) -> Result<impl Display> {
+        let mut ship = Ship::new(stacks.clone(), CrateMover::CM9001);
+        perform_commands(&mut ship, commands)
+    }
+}
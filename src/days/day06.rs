@@ -0,0 +1,41 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::hash::Hash;
+
+use crate::{Error, Result, Solution};
+
+fn has_unique_elements<T>(iter: T) -> bool
+where
+    T: IntoIterator,
+    T::Item: Eq + Hash,
+{
+    let mut uniq = HashSet::new();
+    iter.into_iter().all(move |x| uniq.insert(x))
+}
+
+/// Where the first `n` characters that are all different end, counting from 1.
+pub fn distinct_n_char_location(signal: &[char], n: usize) -> Result<usize> {
+    signal
+        .windows(n)
+        .position(has_unique_elements)
+        .map(|idx| idx + n)
+        .ok_or_else(|| Error::invalid_state(format!("no {} distinct characters in a row", n)))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(input.trim_end().chars().collect())
+    }
+
+    fn part1(signal: &Self::Parsed) -> Result<impl Display> {
+        distinct_n_char_location(signal, 4)
+    }
+
+    fn part2(signal: &Self::Parsed) -> Result<impl Display> {
+        distinct_n_char_location(signal, 14)
+    }
+}
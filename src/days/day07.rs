@@ -0,0 +1,232 @@
+use std::fmt::Display;
+
+use crate::parsers::{decimal, parse_all, separated_all, tag, IResult};
+use crate::{Error, Result, Solution};
+use itertools::Itertools;
+use nom::{
+    branch::alt, bytes::complete::take_while1, character::complete::alphanumeric1,
+    multi::separated_list0,
+};
+
+use indextree::Arena;
+
+pub const SMALL_SIZE_THRESHOLD: usize = 100000;
+pub const TOTAL_DISK_SIZE: usize = 70000000;
+pub const FREE_SPACE_FOR_UPDATE: usize = 30000000;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct File {
+    pub name: String,
+    pub size: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DirectoryContent {
+    Directory(String),
+    File(File),
+}
+
+impl DirectoryContent {
+    pub fn is_root(&self) -> bool {
+        if let DirectoryContent::Directory(root_str) = self {
+            root_str == &"/".to_string()
+        } else {
+            false
+        }
+    }
+
+    pub fn is_parent(&self) -> bool {
+        if let DirectoryContent::Directory(parent_str) = self {
+            parent_str == &"..".to_string()
+        } else {
+            false
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Command {
+    ChangeDirectory(DirectoryContent),
+    List(Vec<DirectoryContent>),
+}
+
+// parsing
+
+fn filename(input: &str) -> IResult<&str, &str> {
+    take_while1(|c: char| !c.is_whitespace())(input)
+}
+
+fn dirname(input: &str) -> IResult<&str, &str> {
+    alt((alphanumeric1, tag("/"), tag("..")))(input)
+}
+
+fn entry_dir(input: &str) -> IResult<&str, DirectoryContent> {
+    let (input, _) = tag("dir")(input)?;
+    let (input, _) = tag(" ")(input)?;
+    let (input, dirname) = dirname(input)?;
+
+    Ok((input, DirectoryContent::Directory(dirname.to_string())))
+}
+
+// Define a parser to parse an entry.
+fn entry_file(input: &str) -> IResult<&str, DirectoryContent> {
+    let (input, size) = decimal(input)?;
+    let (input, _) = tag(" ")(input)?;
+    let (input, filename) = filename(input)?;
+
+    let file = File {
+        name: filename.to_string(),
+        size,
+    };
+
+    Ok((input, DirectoryContent::File(file)))
+}
+
+fn cd(input: &str) -> IResult<&str, Command> {
+    let (input, _) = tag("cd")(input)?;
+    let (input, _) = tag(" ")(input)?;
+    let (input, dir) = dirname(input)?;
+
+    Ok((
+        input,
+        Command::ChangeDirectory(DirectoryContent::Directory(dir.to_string())),
+    ))
+}
+
+fn ls(input: &str) -> IResult<&str, Command> {
+    let (input, _) = tag("ls")(input)?;
+    let (input, _) = tag("\n")(input)?;
+    let (input, entries) = separated_list0(tag("\n"), alt((entry_dir, entry_file)))(input)?;
+
+    Ok((input, Command::List(entries)))
+}
+
+fn command(input: &str) -> IResult<&str, Command> {
+    let (input, _) = tag("$")(input)?;
+    let (input, _) = tag(" ")(input)?;
+    let (input, command) = alt((cd, ls))(input)?;
+
+    Ok((input, command))
+}
+
+fn commands(input: &str) -> IResult<&str, Vec<Command>> {
+    separated_all(tag("\n"), command)(input)
+}
+
+pub fn parse_commands(input: &str) -> Result<Vec<Command>> {
+    parse_all(commands, input)
+}
+
+pub fn commands_to_tree(
+    commands: Vec<Command>,
+) -> Result<(Arena<DirectoryContent>, indextree::NodeId)> {
+    let root_dir = DirectoryContent::Directory("/".to_string());
+    let mut tree = Arena::new();
+    let mut current_node_id = tree.new_node(root_dir);
+    let root_node_id = current_node_id;
+
+    for command in commands {
+        match command {
+            Command::ChangeDirectory(dir) => {
+                if dir.is_root() {
+                    current_node_id = root_node_id;
+                } else if dir.is_parent() {
+                    if let Some(parent_node_id) = tree[current_node_id].parent() {
+                        current_node_id = parent_node_id;
+                    } else {
+                        return Err(Error::invalid_state("cd .. from the root directory"));
+                    }
+                } else {
+                    let maybe_dir = current_node_id
+                        .children(&tree)
+                        .into_iter()
+                        .find(|child| tree[*child].get() == &dir);
+
+                    if let Some(dir) = maybe_dir {
+                        current_node_id = dir;
+                    } else {
+                        return Err(Error::invalid_state(format!(
+                            "cd into {:?}, which has not been listed",
+                            dir
+                        )));
+                    }
+                }
+            }
+            Command::List(contents) => {
+                for content in contents {
+                    let child = tree.new_node(content);
+                    current_node_id.append(child, &mut tree);
+                }
+            }
+        }
+    }
+
+    Ok((tree, root_node_id))
+}
+
+/// Adds the total size of every directory below `current_id` to `size_arena`, and returns the
+/// size of `current_id` itself.
+pub fn populate_size_list(
+    directory_tree: &Arena<DirectoryContent>,
+    current_id: indextree::NodeId,
+    size_arena: &mut Vec<(String, usize)>,
+) -> usize {
+    match directory_tree[current_id].get() {
+        DirectoryContent::File(file) => file.size,
+        DirectoryContent::Directory(name) => {
+            let mut total_size = 0;
+            for child in current_id.children(directory_tree) {
+                let size = populate_size_list(directory_tree, child, size_arena);
+                total_size += size;
+            }
+            size_arena.push((name.clone(), total_size));
+            total_size
+        }
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Vec<(String, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let (tree, root) = commands_to_tree(parse_commands(input)?)?;
+
+        let mut sizes = Vec::new();
+        populate_size_list(&tree, root, &mut sizes);
+
+        Ok(sizes)
+    }
+
+    fn part1(size_list: &Self::Parsed) -> Result<impl Display> {
+        Ok(size_list
+            .iter()
+            .map(|(_, size)| size)
+            .filter(|size| **size <= SMALL_SIZE_THRESHOLD)
+            .sum::<usize>())
+    }
+
+    fn part2(size_list: &Self::Parsed) -> Result<impl Display> {
+        let used_size = size_list
+            .iter()
+            .find(|(name, _size)| name == "/")
+            .ok_or_else(|| Error::invalid_state("no root directory"))?
+            .1;
+        let currently_free = TOTAL_DISK_SIZE.checked_sub(used_size).ok_or_else(|| {
+            Error::invalid_state(format!(
+                "{} used on a disk of {}",
+                used_size, TOTAL_DISK_SIZE
+            ))
+        })?;
+        let need_to_free = FREE_SPACE_FOR_UPDATE.saturating_sub(currently_free);
+
+        size_list
+            .iter()
+            .map(|(_, size)| size)
+            .sorted()
+            .find(|size| **size > need_to_free)
+            .copied()
+            .ok_or_else(|| Error::invalid_state("no directory is big enough to free up space"))
+    }
+}
@@ -0,0 +1,152 @@
+#![allow(clippy::needless_range_loop)]
+
+use std::fmt::Display;
+
+use crate::matrix::{Direction, Matrix};
+use crate::{Result, Solution};
+
+pub fn parse_trees(s: &str) -> Result<Matrix<usize>> {
+    Matrix::from_grid_str(s, "a tree height 0-9", |c| {
+        c.to_digit(10).map(|height| height as usize)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forest {
+    trees: Matrix<usize>,
+}
+
+impl Forest {
+    pub fn new(trees: Matrix<usize>) -> Self {
+        Forest { trees }
+    }
+
+    pub fn trees(&self) -> &Matrix<usize> {
+        &self.trees
+    }
+
+    pub fn number_of_rows(&self) -> usize {
+        self.trees.number_of_rows()
+    }
+
+    pub fn number_of_cols(&self) -> usize {
+        self.trees.number_of_cols()
+    }
+
+    pub fn visible_in_direction(
+        &self,
+        (i, j): (usize, usize),
+        direction: Direction,
+    ) -> impl Iterator<Item = &usize> {
+        let current = self.trees[(i, j)];
+        self.trees
+            .elements_in_direction((i, j), direction)
+            .take_while(move |e| **e < current)
+            .chain(
+                self.trees
+                    .elements_in_direction((i, j), direction)
+                    .find(|x| **x >= current),
+            )
+    }
+
+    pub fn can_see_the_edge_in_direction(
+        &self,
+        (i, j): (usize, usize),
+        direction: Direction,
+    ) -> bool {
+        let current = self.trees[(i, j)];
+        let count = self
+            .visible_in_direction((i, j), direction)
+            .take_while(|e| **e < current)
+            .count();
+        let expected_count = self.trees.elements_in_direction((i, j), direction).count();
+
+        count == expected_count
+    }
+
+    pub fn can_see_the_edge(&self, (i, j): (usize, usize)) -> bool {
+        self.can_see_the_edge_in_direction((i, j), Direction::Left)
+            || self.can_see_the_edge_in_direction((i, j), Direction::Right)
+            || self.can_see_the_edge_in_direction((i, j), Direction::Top)
+            || self.can_see_the_edge_in_direction((i, j), Direction::Bottom)
+    }
+
+    pub fn visible_to_left(&self, idx: (usize, usize)) -> impl IntoIterator<Item = &usize> {
+        self.visible_in_direction(idx, Direction::Left)
+    }
+
+    pub fn visible_to_right(&self, idx: (usize, usize)) -> impl IntoIterator<Item = &usize> {
+        self.visible_in_direction(idx, Direction::Right)
+    }
+
+    pub fn visible_to_top(&self, idx: (usize, usize)) -> impl IntoIterator<Item = &usize> {
+        self.visible_in_direction(idx, Direction::Top)
+    }
+
+    pub fn visible_to_bottom(&self, idx: (usize, usize)) -> impl IntoIterator<Item = &usize> {
+        self.visible_in_direction(idx, Direction::Bottom)
+    }
+}
+
+pub fn scenic_score(forest: &Forest) -> Vec<Vec<usize>> {
+    let rows = forest.number_of_rows();
+    let columns = forest.number_of_cols();
+
+    let mut secenic_score = vec![vec![0; columns]; rows];
+
+    for i in 0..rows {
+        for j in 0..columns {
+            let left = forest.visible_to_left((i, j)).into_iter().count();
+            let right = forest.visible_to_right((i, j)).into_iter().count();
+            let top = forest.visible_to_top((i, j)).into_iter().count();
+            let bottom = forest.visible_to_bottom((i, j)).into_iter().count();
+
+            secenic_score[i][j] = left * right * top * bottom;
+        }
+    }
+
+    secenic_score
+}
+
+pub fn visibility_score(forest: &Forest) -> Vec<Vec<bool>> {
+    let rows = forest.number_of_rows();
+    let columns = forest.number_of_cols();
+
+    let mut visibile = vec![vec![false; columns]; rows];
+
+    for i in 0..rows {
+        for j in 0..columns {
+            visibile[i][j] = forest.can_see_the_edge((i, j));
+        }
+    }
+
+    visibile
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = Forest;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Forest::new(parse_trees(input)?))
+    }
+
+    fn part1(forest: &Self::Parsed) -> Result<impl Display> {
+        let visibility_scores = visibility_score(forest);
+        Ok(visibility_scores
+            .iter()
+            .flat_map(|inner_vec| inner_vec.iter())
+            .filter(|&&b| b)
+            .count())
+    }
+
+    fn part2(forest: &Self::Parsed) -> Result<impl Display> {
+        let scenic_scores = scenic_score(forest);
+        Ok(*scenic_scores
+            .iter()
+            .flat_map(|inner_vec| inner_vec.iter())
+            .max()
+            .unwrap())
+    }
+}
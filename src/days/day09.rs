@@ -0,0 +1,136 @@
+use std::fmt::Display;
+
+use crate::parsers::{decimal, parse_all, separated_all, tag, IResult};
+use crate::sparse::SparseGrid;
+use crate::{Result, Solution};
+use nom::{branch::alt, combinator::map};
+
+pub type Location = (isize, isize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeightedDirection {
+    pub direction: Direction,
+    pub weight: usize,
+}
+
+fn direction(input: &str) -> IResult<&str, Direction> {
+    alt((
+        map(tag("L"), |_| Direction::Left),
+        map(tag("R"), |_| Direction::Right),
+        map(tag("U"), |_| Direction::Top),
+        map(tag("D"), |_| Direction::Bottom),
+    ))(input)
+}
+
+fn weighted_direction(input: &str) -> IResult<&str, WeightedDirection> {
+    let (input, direction) = direction(input)?;
+    let (input, _) = tag(" ")(input)?;
+    let (input, weight) = decimal(input)?;
+    let wd = WeightedDirection { direction, weight };
+
+    Ok((input, wd))
+}
+
+pub fn move_in_direction((start_x, start_y): Location, direction: Direction) -> Location {
+    match direction {
+        Direction::Left => (start_x - 1, start_y),
+        Direction::Right => (start_x + 1, start_y),
+        Direction::Top => (start_x, start_y + 1),
+        Direction::Bottom => (start_x, start_y - 1),
+    }
+}
+
+/// A rope whose knots all start at the origin, the first of them being the head.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rope {
+    knots: Vec<Location>,
+}
+
+impl Rope {
+    pub fn new(length: usize) -> Self {
+        Self {
+            knots: vec![(0, 0); length],
+        }
+    }
+
+    pub fn length(&self) -> usize {
+        self.knots.len()
+    }
+
+    pub fn move_head(&mut self, direction: Direction) {
+        self.knots[0] = move_in_direction(self.knots[0], direction);
+        for index in 1..self.knots.len() {
+            self.update_knot(index);
+        }
+    }
+
+    fn update_knot(&mut self, index: usize) {
+        let (head_x, head_y) = self.knots[index - 1];
+        let (tail_x, tail_y) = self.knots[index];
+        let (dx, dy) = (head_x - tail_x, head_y - tail_y);
+        let is_far = dx.abs() > 1 || dy.abs() > 1;
+
+        let (tdx, tdy) = if is_far {
+            (dx.signum(), dy.signum())
+        } else {
+            (0, 0)
+        };
+
+        self.knots[index] = (tail_x + tdx, tail_y + tdy)
+    }
+
+    pub fn tail_location(&self, index: usize) -> Location {
+        self.knots[index]
+    }
+
+    pub fn knots(&self) -> &[Location] {
+        &self.knots
+    }
+}
+
+pub fn unique_locations_visited_by_tail(
+    rope: &mut Rope,
+    directions: &[WeightedDirection],
+) -> usize {
+    let tail_index = rope.length() - 1;
+
+    let mut visited_by_tail = SparseGrid::new();
+    visited_by_tail.insert((0, 0), ());
+
+    for WeightedDirection { direction, weight } in directions {
+        for _step in 0..*weight {
+            rope.move_head(*direction);
+            visited_by_tail.insert(rope.tail_location(tail_index), ());
+        }
+    }
+
+    visited_by_tail.len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Vec<WeightedDirection>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_all(separated_all(tag("\n"), weighted_direction), input)
+    }
+
+    fn part1(directions: &Self::Parsed) -> Result<impl Display> {
+        let mut rope = Rope::new(2);
+        Ok(unique_locations_visited_by_tail(&mut rope, directions))
+    }
+
+    fn part2(directions: &Self::Parsed) -> Result<impl Display> {
+        let mut rope = Rope::new(10);
+        Ok(unique_locations_visited_by_tail(&mut rope, directions))
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+use crate::parsers::{parse_all, separated_all, signed_decimal, tag, IResult};
+use crate::{Result, Solution};
+use itertools::Itertools;
+use nom::{branch::alt, combinator::map};
+
+pub const INSTRUCTIONS_TO_SKIP: usize = 19;
+pub const CRT_SIZE: usize = 40;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    AddX(isize),
+    NoOp,
+}
+
+fn no_op(input: &str) -> IResult<&str, Instruction> {
+    map(tag("noop"), |_| Instruction::NoOp)(input)
+}
+
+fn add_x(input: &str) -> IResult<&str, Instruction> {
+    let (input, _) = tag("addx")(input)?;
+    let (input, _) = tag(" ")(input)?;
+    let (input, v) = signed_decimal(input)?;
+
+    Ok((input, Instruction::AddX(v)))
+}
+
+fn instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
+    separated_all(tag("\n"), alt((add_x, no_op)))(input)
+}
+
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>> {
+    parse_all(instructions, input)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cpu {
+    register: usize,
+    sprite_positions: Vec<usize>,
+}
+
+impl Cpu {
+    pub fn perform_instruction(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::AddX(new_val) => {
+                self.sprite_positions.push(self.register);
+                self.register = (self.register as isize + new_val) as usize;
+            }
+            Instruction::NoOp => {}
+        };
+        self.sprite_positions.push(self.register);
+    }
+
+    pub fn register(&self) -> usize {
+        self.register
+    }
+
+    /// The sprite position during each cycle so far, along with the cycle, counting from 1.
+    pub fn steps(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (1..).zip(self.sprite_positions.iter().copied())
+    }
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Cpu {
+            register: 1,
+            sprite_positions: vec![1],
+        }
+    }
+}
+
+pub fn perform_instructions(cpu: &mut Cpu, instructions: impl IntoIterator<Item = Instruction>) {
+    for instruction in instructions {
+        cpu.perform_instruction(instruction);
+    }
+}
+
+pub fn crt_position_at_cycle(cycle: usize) -> usize {
+    (cycle - 1) % CRT_SIZE
+}
+
+pub fn crt_sprite_overlaps_with_current_pixel(sprite_center: usize, current_pixel: usize) -> bool {
+    current_pixel as isize >= (sprite_center as isize - 1)
+        && current_pixel as isize <= (sprite_center as isize + 1)
+}
+
+pub fn crt_draw(cycle: usize, sprite_center: usize) -> char {
+    let current_pixel = crt_position_at_cycle(cycle);
+    if crt_sprite_overlaps_with_current_pixel(sprite_center, current_pixel) {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Cpu;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut cpu = Cpu::default();
+        perform_instructions(&mut cpu, parse_instructions(input)?);
+
+        Ok(cpu)
+    }
+
+    fn part1(cpu: &Self::Parsed) -> Result<impl Display> {
+        Ok(cpu
+            .steps()
+            .skip(INSTRUCTIONS_TO_SKIP)
+            .step_by(CRT_SIZE)
+            .map(|(idx, register)| idx * register)
+            .sum::<usize>())
+    }
+
+    fn part2(cpu: &Self::Parsed) -> Result<impl Display> {
+        Ok(cpu
+            .steps()
+            .map(|(c, sp)| crt_draw(c, sp))
+            .chunks(CRT_SIZE)
+            .into_iter()
+            .map(|row| row.into_iter().collect::<String>())
+            .join("\n"))
+    }
+}
@@ -0,0 +1,282 @@
+use itertools::Itertools;
+use std::{collections::VecDeque, fmt::Display};
+
+use crate::parsers::{context, decimal, parse_all, separated_all, tag, IResult};
+use crate::{itertools::Lcm, Error, Result, Solution};
+use nom::{branch::alt, character::complete::space0, combinator::map, multi::separated_list0};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Mult(u128),
+    Add(u128),
+    Pow,
+}
+
+impl Operation {
+    pub fn updated_worry_level(&self, worry_level: u128) -> u128 {
+        match self {
+            Operation::Mult(m) => worry_level * m,
+            Operation::Add(a) => worry_level + a,
+            Operation::Pow => worry_level * worry_level,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IfThenElse {
+    pub divisible_by: u128,
+    pub case_success: usize,
+    pub case_failure: usize,
+}
+
+impl IfThenElse {
+    pub fn throw_to(&self, worry_level: u128) -> usize {
+        if worry_level.is_multiple_of(self.divisible_by) {
+            self.case_success
+        } else {
+            self.case_failure
+        }
+    }
+}
+
+/// What is known about a monkey. Notes only come from [`parse_notes`], which checks that they
+/// fit together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Note {
+    monkey_index: usize,
+    items: Vec<u128>,
+    operation: Operation,
+    test: IfThenElse,
+}
+
+impl Note {
+    pub fn monkey_index(&self) -> usize {
+        self.monkey_index
+    }
+
+    pub fn items(&self) -> &[u128] {
+        &self.items
+    }
+
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    pub fn test(&self) -> IfThenElse {
+        self.test
+    }
+}
+
+fn monkey(input: &str) -> IResult<&str, usize> {
+    let (input, _) = tag("Monkey ")(input)?;
+    let (input, index) = decimal(input)?;
+    let (input, _) = tag(":\n")(input)?;
+
+    Ok((input, index))
+}
+
+fn start_items(input: &str) -> IResult<&str, Vec<u128>> {
+    let (input, _) = space0(input)?;
+    let (input, _) = tag("Starting items: ")(input)?;
+    let (input, items) = separated_list0(tag(", "), map(decimal, |d| d as u128))(input)?;
+    let (input, _) = tag("\n")(input)?;
+
+    Ok((input, items))
+}
+
+fn mult(input: &str) -> IResult<&str, Operation> {
+    let (input, _) = tag("*")(input)?;
+    let (input, _) = space0(input)?;
+    let (input, op) = alt((
+        map(decimal, |d| Operation::Mult(d as u128)),
+        map(tag("old"), |_| Operation::Pow),
+    ))(input)?;
+
+    Ok((input, op))
+}
+
+fn add(input: &str) -> IResult<&str, Operation> {
+    let (input, _) = tag("+")(input)?;
+    let (input, _) = space0(input)?;
+    let (input, val) = decimal(input)?;
+
+    Ok((input, Operation::Add(val as u128)))
+}
+
+fn operation(input: &str) -> IResult<&str, Operation> {
+    let (input, _) = space0(input)?;
+    let (input, _) = tag("Operation: new = old ")(input)?;
+    let (input, op) = alt((mult, add))(input)?;
+    let (input, _) = tag("\n")(input)?;
+
+    Ok((input, op))
+}
+
+fn if_then_else(input: &str) -> IResult<&str, IfThenElse> {
+    let (input, _) = space0(input)?;
+    let (input, _) = tag("Test: divisible by ")(input)?;
+    let (input, divisible_by) = map(decimal, |d| d as u128)(input)?;
+    let (input, _) = tag("\n")(input)?;
+    let (input, _) = space0(input)?;
+    let (input, _) = tag("If true: throw to monkey ")(input)?;
+    let (input, case_success) = decimal(input)?;
+    let (input, _) = tag("\n")(input)?;
+    let (input, _) = space0(input)?;
+    let (input, _) = tag("If false: throw to monkey ")(input)?;
+    let (input, case_failure) = decimal(input)?;
+    let (input, _) = tag("\n")(input)?;
+
+    let if_then_else = IfThenElse {
+        divisible_by,
+        case_success,
+        case_failure,
+    };
+
+    Ok((input, if_then_else))
+}
+
+fn note(input: &str) -> IResult<&str, Note> {
+    let (input, monkey_index) = monkey(input)?;
+    let label = format!("monkey note {}", monkey_index);
+    let (input, items) = context(label.clone(), context("Starting items", start_items))(input)?;
+    let (input, operation) = context(label.clone(), context("Operation", operation))(input)?;
+    let (input, test) = context(label, context("Test", if_then_else))(input)?;
+
+    let entry = Note {
+        monkey_index,
+        items,
+        operation,
+        test,
+    };
+
+    Ok((input, entry))
+}
+
+fn check_notes(notes: &[Note]) -> Result<()> {
+    if notes.is_empty() {
+        return Err(Error::invalid_state("there are no monkeys"));
+    }
+
+    for (i, note) in notes.iter().enumerate() {
+        if note.monkey_index != i {
+            return Err(Error::invalid_state(format!(
+                "note {} is for monkey {}",
+                i, note.monkey_index
+            )));
+        }
+        if note.test.divisible_by == 0 {
+            return Err(Error::invalid_state(format!(
+                "monkey {} tests for divisibility by 0",
+                i
+            )));
+        }
+        for target in [note.test.case_success, note.test.case_failure] {
+            if target >= notes.len() {
+                return Err(Error::invalid_state(format!(
+                    "monkey {} throws to monkey {}, which does not exist",
+                    i, target
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn parse_notes(input: &str) -> Result<Vec<Note>> {
+    let notes = parse_all(separated_all(tag("\n"), note), input)?;
+    check_notes(&notes)?;
+
+    Ok(notes)
+}
+
+pub struct MonkeyBusiness<'a> {
+    worries: Vec<VecDeque<u128>>,
+    passes: Vec<usize>,
+    rounds: usize,
+    worry_manager: &'a dyn Fn(u128) -> u128,
+    modulo: u128,
+}
+
+impl<'a> MonkeyBusiness<'a> {
+    pub fn new(notes: &[Note], rounds: usize, worry_manager: &'a dyn Fn(u128) -> u128) -> Self {
+        let mut worries: Vec<VecDeque<u128>> = vec![VecDeque::new(); notes.len()];
+        let passes: Vec<usize> = vec![0; notes.len()];
+
+        let modulo = notes.iter().map(|c| c.test.divisible_by).lcm().unwrap();
+
+        // initial setup
+        for i in 0..notes.len() {
+            worries[i] = notes[i].items.clone().into();
+        }
+
+        Self {
+            worries,
+            passes,
+            rounds,
+            worry_manager,
+            modulo,
+        }
+    }
+
+    fn execute_command(&mut self, note: &Note) {
+        let i = note.monkey_index;
+        while let Some(worry_level) = self.worries[i].pop_front() {
+            self.passes[i] += 1;
+            let increased_worry_level = note.operation.updated_worry_level(worry_level);
+
+            // Perform modulo arithmatic so that the numbers don't get too large
+            let managed_worry_level =
+                ((self.worry_manager)(increased_worry_level)).rem_euclid(self.modulo);
+
+            let throw_to = note.test.throw_to(managed_worry_level);
+
+            self.worries[throw_to].push_back(managed_worry_level)
+        }
+    }
+
+    pub fn run(&mut self, notes: &[Note]) {
+        for _round in 0..self.rounds {
+            for note in notes {
+                self.execute_command(note)
+            }
+        }
+    }
+
+    /// How many items each monkey has inspected.
+    pub fn passes(&self) -> &[usize] {
+        &self.passes
+    }
+
+    pub fn monkey_business(&self) -> usize {
+        self.passes
+            .iter()
+            .sorted()
+            .copied()
+            .rev()
+            .take(2)
+            .product::<usize>()
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<Note>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_notes(input)
+    }
+
+    fn part1(notes: &Self::Parsed) -> Result<impl Display> {
+        let mut monkey_business = MonkeyBusiness::new(notes, 20, &|w| w / 3);
+        monkey_business.run(notes);
+        Ok(monkey_business.monkey_business())
+    }
+
+    fn part2(notes: &Self::Parsed) -> Result<impl Display> {
+        let mut monkey_business = MonkeyBusiness::new(notes, 10000, &|w| w);
+        monkey_business.run(notes);
+        Ok(monkey_business.monkey_business())
+    }
+}
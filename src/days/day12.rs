@@ -0,0 +1,139 @@
+use std::fmt::Display;
+
+use crate::search::{self, Path};
+use crate::{matrix::Matrix, Error, Result, Solution};
+
+pub type Location = (usize, usize);
+
+pub fn parse_hill(input: &str) -> Result<Hill> {
+    let (heights, markers) =
+        Matrix::from_grid_str_with_markers(input, &['S', 'E'], "an elevation a-z, S or E", |c| {
+            match c {
+                'S' => Some(0),
+                'E' => Some(26),
+                'a'..='z' => Some(c as usize - 'a' as usize),
+                _ => None,
+            }
+        })?;
+    let marker = |marker| {
+        markers
+            .iter()
+            .find(|(c, _)| *c == marker)
+            .map(|&(_, location)| location)
+    };
+
+    match (marker('S'), marker('E')) {
+        (Some(start), Some(end)) => Ok(Hill {
+            heights,
+            start,
+            end,
+        }),
+        (None, _) => Err(Error::invalid_state("the heightmap has no start marker S")),
+        (_, None) => Err(Error::invalid_state("the heightmap has no end marker E")),
+    }
+}
+
+/// A heightmap, with elevations from 0 for `a` to 25 for `z`. The start is at elevation 0 and the
+/// end at 26, one above `z`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hill {
+    heights: Matrix<usize>,
+    start: Location,
+    end: Location,
+}
+
+impl Hill {
+    pub fn heights(&self) -> &Matrix<usize> {
+        &self.heights
+    }
+
+    pub fn start(&self) -> Location {
+        self.start
+    }
+
+    pub fn end(&self) -> Location {
+        self.end
+    }
+
+    /// Every location at elevation 0, the start included.
+    pub fn lowest_points(&self) -> impl Iterator<Item = Location> + '_ {
+        let (rows, cols) = (self.heights.number_of_rows(), self.heights.number_of_cols());
+        (0..rows)
+            .flat_map(move |i| (0..cols).map(move |j| (i, j)))
+            .filter(|&loc| self.heights[loc] == 0)
+    }
+
+    /// The locations that can be climbed to from `loc`, which are at most one higher.
+    pub fn neighbours(&self, loc: Location) -> impl Iterator<Item = Location> + '_ {
+        let location_elevation = self.heights[loc];
+        self.heights
+            .neighbouring_indices(loc)
+            .filter(move |(i, j)| self.heights[(*i, *j)] <= location_elevation + 1)
+    }
+
+    /// The locations `loc` can be climbed to from, which are at most one lower.
+    pub fn descents(&self, loc: Location) -> impl Iterator<Item = Location> + '_ {
+        let location_elevation = self.heights[loc];
+        self.heights
+            .neighbouring_indices(loc)
+            .filter(move |&n| location_elevation <= self.heights[n] + 1)
+    }
+
+    /// The fewest steps it takes to climb from each location to `end`, or `None` for those it
+    /// cannot be climbed to from.
+    pub fn distances_to(&self, end: Location) -> Matrix<Option<usize>> {
+        let mut distances =
+            Matrix::new((self.heights.number_of_rows(), self.heights.number_of_cols()));
+        for (loc, steps) in search::distances([end], |&loc| self.descents(loc)) {
+            distances[loc] = Some(steps);
+        }
+        distances
+    }
+
+    /// The shortest way to climb to the end from any location at elevation 0, found by
+    /// descending from the end until one is reached.
+    pub fn nearest_lowest_point(&self) -> Option<Path<Location, usize>> {
+        let mut path = search::bfs(
+            [self.end],
+            |&loc| self.descents(loc),
+            |&loc| self.heights[loc] == 0,
+        )?;
+        path.nodes.reverse();
+        Some(path)
+    }
+
+    /// The fewest steps it takes to climb from `start` to `end`, if it can be done at all.
+    pub fn search(&self, start: Location, end: Location) -> Option<usize> {
+        self.climb([start], end).map(|path| path.cost)
+    }
+
+    /// The shortest way to climb from the nearest of `starts` to `end`.
+    pub fn climb(
+        &self,
+        starts: impl IntoIterator<Item = Location>,
+        end: Location,
+    ) -> Option<Path<Location, usize>> {
+        search::bfs(starts, |&loc| self.neighbours(loc), |&loc| loc == end)
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Hill;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_hill(input)
+    }
+
+    fn part1(hill: &Self::Parsed) -> Result<impl Display> {
+        hill.search(hill.start(), hill.end())
+            .ok_or_else(|| Error::invalid_state("there is no way from S to E"))
+    }
+
+    fn part2(hill: &Self::Parsed) -> Result<impl Display> {
+        hill.nearest_lowest_point()
+            .map(|path| path.cost)
+            .ok_or_else(|| Error::invalid_state("there is no way from elevation a to E"))
+    }
+}
@@ -0,0 +1,134 @@
+use crate::parsers::{decimal, parse_all, separated_all, tag, IResult};
+use crate::{Error, Result, Solution};
+use nom::multi::separated_list0;
+use nom::{branch::alt, character::complete::char, sequence::delimited};
+
+use itertools::EitherOrBoth::{Both, Left, Right};
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub enum Element {
+    Base(isize),
+    List(Vec<Element>),
+}
+
+impl PartialEq for Element {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Element::Base(lhs), Element::Base(rhs)) => lhs.eq(rhs),
+            (Element::List(lhs), Element::List(rhs)) => lhs.eq(rhs),
+            (lhs @ Element::List(_), Element::Base(rhs)) => {
+                lhs.eq(&Element::List(vec![Element::Base(*rhs)]))
+            }
+            (Element::Base(lhs), rhs @ Element::List(_)) => {
+                Element::List(vec![Element::Base(*lhs)]).eq(rhs)
+            }
+        }
+    }
+}
+
+impl Eq for Element {}
+
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Element {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Element::Base(lhs), Element::Base(rhs)) => lhs.cmp(rhs),
+            (Element::List(lhs), Element::List(rhs)) => {
+                for zipped in lhs.iter().zip_longest(rhs.iter()) {
+                    match zipped {
+                        Both(lhs_element, rhs_element) => {
+                            let cmp = lhs_element.cmp(rhs_element);
+                            if cmp != Ordering::Equal {
+                                return cmp;
+                            }
+                        }
+                        Left(_) => return Ordering::Greater,
+                        Right(_) => return Ordering::Less,
+                    }
+                }
+                Ordering::Equal
+            }
+            (lhs @ Element::List(_), Element::Base(rhs)) => {
+                lhs.cmp(&Element::List(vec![Element::Base(*rhs)]))
+            }
+            (Element::Base(lhs), rhs @ Element::List(_)) => {
+                Element::List(vec![Element::Base(*lhs)]).cmp(rhs)
+            }
+        }
+    }
+}
+
+fn base(input: &str) -> IResult<&str, Element> {
+    let (input, base) = decimal(input)?;
+    Ok((input, Element::Base(base as isize)))
+}
+
+fn list(input: &str) -> IResult<&str, Element> {
+    delimited(
+        char('['),
+        separated_list0(tag(","), alt((list, base))),
+        char(']'),
+    )(input)
+    .map(|(input, list)| (input, Element::List(list)))
+}
+
+impl FromStr for Element {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_all(list, s)
+    }
+}
+
+pub fn parse_packets(input: &str) -> Result<Vec<Element>> {
+    parse_all(separated_all(alt((tag("\n\n"), tag("\n"))), list), input)
+}
+
+/// Sorts the packets along with the divider packets `[[2]]` and `[[6]]`, and multiplies where
+/// the dividers end up.
+pub fn divider_packets_decoder_key(packets: &[Element]) -> usize {
+    let marker1 = Element::List(vec![Element::List(vec![Element::Base(2)])]);
+    let marker2 = Element::List(vec![Element::List(vec![Element::Base(6)])]);
+
+    packets
+        .iter()
+        .chain([&marker1, &marker2])
+        .sorted()
+        .enumerate()
+        .filter(|(_, element)| **element == marker1 || **element == marker2)
+        .map(|(i, _)| i + 1)
+        .product::<usize>()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<Element>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_packets(input)
+    }
+
+    fn part1(list: &Self::Parsed) -> Result<impl Display> {
+        Ok(list
+            .iter()
+            .tuples::<(_, _)>()
+            .enumerate()
+            .filter(|(_, (first, second))| first < second)
+            .map(|(i, _)| i + 1)
+            .sum::<usize>())
+    }
+
+    fn part2(list: &Self::Parsed) -> Result<impl Display> {
+        Ok(divider_packets_decoder_key(list))
+    }
+}
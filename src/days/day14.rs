@@ -0,0 +1,243 @@
+use std::{cmp::Ordering, collections::HashSet, fmt, fmt::Display};
+
+use crate::parsers::{decimal, parse_all, separated_all, tag, IResult};
+use crate::sparse::{Point, SparseGrid};
+use crate::{Error, Result, Solution};
+use itertools::Itertools;
+use nom::multi::separated_list1;
+
+pub type Location = (usize, usize);
+pub type Path = Vec<Location>;
+
+fn point(input: &str) -> IResult<&str, Location> {
+    let (input, x) = decimal(input)?;
+    let (input, _) = tag(",")(input)?;
+    let (input, y) = decimal(input)?;
+
+    Ok((input, (x, y)))
+}
+
+fn path(input: &str) -> IResult<&str, Path> {
+    separated_list1(tag(" -> "), point)(input)
+}
+
+pub fn line((start_x, start_y): Location, (end_x, end_y): Location) -> Result<Vec<Location>> {
+    match (end_x.cmp(&start_x), end_y.cmp(&start_y)) {
+        (Ordering::Less, Ordering::Equal) => Ok((end_x..=start_x).map(|x| (x, end_y)).collect()),
+        (Ordering::Greater, Ordering::Equal) => Ok((start_x..=end_x).map(|x| (x, end_y)).collect()),
+        (Ordering::Equal, Ordering::Less) => Ok((end_y..=start_y).map(|y| (end_x, y)).collect()),
+        (Ordering::Equal, Ordering::Greater) => Ok((start_y..=end_y).map(|y| (end_x, y)).collect()),
+        (Ordering::Equal, Ordering::Equal) => Ok(vec![(start_x, start_y)]),
+        _ => Err(Error::invalid_state(format!(
+            "rock path from {:?} to {:?} is not a straight line",
+            (start_x, start_y),
+            (end_x, end_y)
+        ))),
+    }
+}
+
+pub fn build_reserviour(paths: Vec<Path>) -> Result<HashSet<Location>> {
+    let mut rocks = HashSet::new();
+
+    for path in paths {
+        for (start, end) in path.iter().tuple_windows() {
+            rocks.extend(line(*start, *end)?);
+        }
+    }
+
+    if rocks.is_empty() {
+        return Err(Error::invalid_state("there are no rocks in the reserviour"));
+    }
+
+    Ok(rocks)
+}
+
+pub fn parse_rocks(input: &str) -> Result<HashSet<Location>> {
+    build_reserviour(parse_all(separated_all(tag("\n"), path), input)?)
+}
+
+enum StepResult {
+    OutOfBounds,
+    NoChange,
+    Change(Location),
+}
+
+enum ParticaleResult {
+    Settled(Location),
+    IntoTheAbyss,
+}
+
+/// How many particles of sand came to rest before the rest started falling into the abyss, or
+/// before the source got blocked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimulationResult {
+    IntoTheAbyss(usize),
+    FilledToBrim(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Rock,
+    Sand,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reserviour {
+    tiles: SparseGrid<Tile>,
+    floor: Option<usize>,
+    start: Location,
+}
+
+fn grid_point((x, y): Location) -> Point {
+    (y as isize, x as isize)
+}
+
+impl Reserviour {
+    pub fn new(rocks: HashSet<Location>, start: Location) -> Result<Self> {
+        if rocks.is_empty() {
+            return Err(Error::invalid_state("there are no rocks in the reserviour"));
+        }
+
+        Ok(Reserviour {
+            tiles: rocks
+                .into_iter()
+                .map(|rock| (grid_point(rock), Tile::Rock))
+                .collect(),
+            floor: None,
+            start,
+        })
+    }
+
+    /// Like [`Reserviour::new`], with an endless floor two below the lowest rock.
+    pub fn new_with_base(rocks: HashSet<Location>, start: Location) -> Result<Self> {
+        let lowest = rocks.iter().map(|&(_, y)| y).max();
+        let mut reserviour = Reserviour::new(rocks, start)?;
+        reserviour.floor = lowest.map(|lowest| lowest + 2);
+        Ok(reserviour)
+    }
+
+    fn step(&mut self, (start_x, start_y): Location) -> StepResult {
+        let new_location = (start_x, start_y + 1);
+        if self.out_of_bounds(&new_location) {
+            StepResult::OutOfBounds
+        } else if self.occupied(&new_location) {
+            let new_location = (start_x - 1, start_y + 1);
+            if self.out_of_bounds(&new_location) {
+                StepResult::OutOfBounds
+            } else if self.occupied(&new_location) {
+                let new_location = (start_x + 1, start_y + 1);
+                if self.out_of_bounds(&new_location) {
+                    StepResult::OutOfBounds
+                } else if self.occupied(&new_location) {
+                    StepResult::NoChange
+                } else {
+                    StepResult::Change(new_location)
+                }
+            } else {
+                StepResult::Change(new_location)
+            }
+        } else {
+            StepResult::Change(new_location)
+        }
+    }
+
+    fn block(&mut self, location: &Location) {
+        self.tiles.insert(grid_point(*location), Tile::Sand);
+    }
+
+    pub fn occupied(&self, location: &Location) -> bool {
+        self.tiles.contains(grid_point(*location)) || Some(location.1) == self.floor
+    }
+
+    /// Whether sand at `location` has fallen past the rocks. Nothing does once there is a floor.
+    fn out_of_bounds(&self, &location: &Location) -> bool {
+        if self.floor.is_some() {
+            return false;
+        }
+
+        let (i, j) = grid_point(location);
+        match self.tiles.bounds() {
+            Some(((_, left), (bottom, right))) => j < left || j > right || i > bottom,
+            None => true,
+        }
+    }
+
+    fn simulate_particle(&mut self) -> ParticaleResult {
+        let mut location = self.start;
+        loop {
+            match self.step(location) {
+                StepResult::OutOfBounds => return ParticaleResult::IntoTheAbyss,
+                StepResult::NoChange => return ParticaleResult::Settled(location),
+                StepResult::Change(new_location) => location = new_location,
+            }
+        }
+    }
+
+    pub fn simulate(&mut self) -> SimulationResult {
+        let mut particle_number = 0;
+        loop {
+            match self.simulate_particle() {
+                ParticaleResult::Settled(location) => {
+                    // The particle blocking the source came to rest too, so it counts
+                    if location == self.start {
+                        return SimulationResult::FilledToBrim(particle_number + 1);
+                    }
+                    self.block(&location);
+                    particle_number += 1;
+                }
+                ParticaleResult::IntoTheAbyss => {
+                    return SimulationResult::IntoTheAbyss(particle_number)
+                }
+            }
+        }
+    }
+}
+
+/// Rock `#`, sand `o`, air `.` and the source of the sand `+`, one column either side of the rocks
+/// and sand.
+impl fmt::Display for Reserviour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ((_, left), (bottom, right)) = self.tiles.bounds().ok_or(fmt::Error)?;
+        let (start_row, start_col) = grid_point(self.start);
+        let bottom = self.floor.map_or(bottom, |floor| floor as isize);
+        let top_left = (start_row.min(0), left.min(start_col) - 1);
+        let bottom_right = (bottom, right.max(start_col) + 1);
+
+        let picture = self
+            .tiles
+            .render_area(top_left, bottom_right, |(i, j), tile| match tile {
+                _ if (i, j) == (start_row, start_col) => '+',
+                Some(Tile::Rock) => '#',
+                Some(Tile::Sand) => 'o',
+                None if Some(i) == self.floor.map(|floor| floor as isize) => '#',
+                None => '.',
+            });
+        f.write_str(&picture)
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = HashSet<Location>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_rocks(input)
+    }
+
+    fn part1(rocks: &Self::Parsed) -> Result<impl Display> {
+        let mut reserviour = Reserviour::new(rocks.clone(), (500, 0))?;
+        match reserviour.simulate() {
+            SimulationResult::IntoTheAbyss(particles)
+            | SimulationResult::FilledToBrim(particles) => Ok(particles),
+        }
+    }
+
+    fn part2(rocks: &Self::Parsed) -> Result<impl Display> {
+        let mut reserviour = Reserviour::new_with_base(rocks.clone(), (500, 0))?;
+        match reserviour.simulate() {
+            SimulationResult::IntoTheAbyss(particles)
+            | SimulationResult::FilledToBrim(particles) => Ok(particles),
+        }
+    }
+}
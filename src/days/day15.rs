@@ -0,0 +1,195 @@
+use crate::parsers::{context, parse_all, separated_all, signed_decimal, tag, IResult};
+use crate::range::{AaBox, RangeSet};
+use crate::{Error, Result, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+pub const PART1_ROW: isize = 2000000;
+pub const SEARCH_LIMIT: isize = 4000000;
+
+pub type Location = (isize, isize);
+
+/// A sensor and the closest beacon to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pair {
+    pub sensor: Location,
+    pub beacon: Location,
+}
+
+impl Pair {
+    pub fn manhattan(&self) -> usize {
+        let (sensor_x, sensor_y) = self.sensor;
+        let (beacon_x, beacon_y) = self.beacon;
+
+        (beacon_x - sensor_x).unsigned_abs() + (beacon_y - sensor_y).unsigned_abs()
+    }
+
+    /// The positions in row `y` that are no further from the sensor than its beacon is.
+    pub fn range_with_manhattan(&self, y: isize) -> Option<RangeInclusive<isize>> {
+        let m_distance = self.manhattan();
+        if self.is_too_far(y) {
+            None
+        } else {
+            let (sensor_x, sensor_y) = self.sensor;
+            let remaining_m_distance = m_distance - (y - sensor_y).unsigned_abs();
+
+            Some(
+                (sensor_x - remaining_m_distance as isize)
+                    ..=(sensor_x + remaining_m_distance as isize),
+            )
+        }
+    }
+
+    /// Whether `location` is no further from the sensor than its beacon is.
+    pub fn covers(&self, (x, y): Location) -> bool {
+        let (sensor_x, sensor_y) = self.sensor;
+        (x - sensor_x).unsigned_abs() + (y - sensor_y).unsigned_abs() <= self.manhattan()
+    }
+
+    pub fn is_too_far(&self, y: isize) -> bool {
+        let (_sensor_x, sensor_y) = self.sensor;
+        let m_distance = self.manhattan();
+
+        (y - sensor_y).unsigned_abs() > m_distance
+    }
+}
+
+// x=2, y=18
+fn location(input: &str) -> IResult<&str, Location> {
+    let (input, _) = tag("x=")(input)?;
+    let (input, x) = signed_decimal(input)?;
+    let (input, _) = tag(", ")(input)?;
+    let (input, _) = tag("y=")(input)?;
+    let (input, y) = signed_decimal(input)?;
+
+    Ok((input, (x, y)))
+}
+
+fn sensor(input: &str) -> IResult<&str, Location> {
+    let (input, _) = tag("Sensor at ")(input)?;
+    let (input, loc) = location(input)?;
+
+    Ok((input, loc))
+}
+
+fn beacon(input: &str) -> IResult<&str, Location> {
+    let (input, _) = tag("closest beacon is at ")(input)?;
+    let (input, loc) = location(input)?;
+
+    Ok((input, loc))
+}
+
+fn pair(input: &str) -> IResult<&str, Pair> {
+    let (input, sensor) = context("sensor", sensor)(input)?;
+    let (input, _) = tag(": ")(input)?;
+    let (input, beacon) = context("beacon", beacon)(input)?;
+
+    Ok((input, Pair { sensor, beacon }))
+}
+
+/// The positions in row `y` that some sensor is at least as close to as its beacon.
+pub fn covered_in_row(pairs: &[Pair], y: isize) -> RangeSet<isize> {
+    pairs
+        .iter()
+        .filter_map(|p| p.range_with_manhattan(y))
+        .collect()
+}
+
+fn frequency((x, y): Location) -> isize {
+    x * 4000000 + y
+}
+
+/// Finds the distress beacon by looking for the one position in `region` that no sensor covers,
+/// one row at a time.
+pub fn tuning_frequency_by_rows(pairs: &[Pair], region: &AaBox<isize, 2>) -> Result<isize> {
+    let [sensor_range_x, sensor_range_y] = region.axes().clone();
+
+    for y in sensor_range_y {
+        let uncovered = covered_in_row(pairs, y).complement(sensor_range_x.clone());
+
+        match uncovered.ranges() {
+            [] => continue,
+            [gap] if gap.start() == gap.end() => return Ok(frequency((*gap.start(), y))),
+            [gap] => {
+                return Err(Error::invalid_state(format!(
+                    "row {} has a gap {:?} that is not a single position",
+                    y, gap
+                )))
+            }
+            _ => {
+                return Err(Error::invalid_state(format!(
+                    "row {} has more than one gap, so the distress beacon is not unique",
+                    y
+                )));
+            }
+        }
+    }
+
+    Err(Error::invalid_state("no position for the distress beacon"))
+}
+
+/// A position in `region` that no sensor covers, only checking where the lines just outside the
+/// edges of the sensors' diamonds cross, as a single uncovered position must be boxed in by them.
+/// The corners of `region` are checked too, as the edges of the region can box it in as well.
+pub fn distress_beacon(pairs: &[Pair], region: &AaBox<isize, 2>) -> Option<Location> {
+    // Lines going down to the right are x - y = a, those going up to the right x + y = b
+    let mut falling = HashSet::new();
+    let mut rising = HashSet::new();
+    for pair in pairs {
+        let (x, y) = pair.sensor;
+        let outside = pair.manhattan() as isize + 1;
+        falling.extend([x - y - outside, x - y + outside]);
+        rising.extend([x + y - outside, x + y + outside]);
+    }
+
+    let [xs, ys] = region.axes();
+    let corners = [
+        (*xs.start(), *ys.start()),
+        (*xs.start(), *ys.end()),
+        (*xs.end(), *ys.start()),
+        (*xs.end(), *ys.end()),
+    ];
+    let crossings = falling.iter().flat_map(|a| {
+        rising
+            .iter()
+            .filter(move |b| (*b - a) % 2 == 0)
+            .map(move |b| ((a + b) / 2, (b - a) / 2))
+    });
+
+    corners
+        .into_iter()
+        .chain(crossings)
+        .find(|&(x, y)| region.contains(&[x, y]) && !pairs.iter().any(|p| p.covers((x, y))))
+}
+
+/// Like [`tuning_frequency_by_rows`], finding the distress beacon with [`distress_beacon`] instead,
+/// and only scanning the rows if that finds nothing.
+pub fn tuning_frequency(pairs: &[Pair], region: &AaBox<isize, 2>) -> Result<isize> {
+    match distress_beacon(pairs, region) {
+        Some(location) => Ok(frequency(location)),
+        None => tuning_frequency_by_rows(pairs, region),
+    }
+}
+
+pub fn parse_pairs(input: &str) -> Result<Vec<Pair>> {
+    parse_all(separated_all(tag("\n"), pair), input)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_pairs(input)
+    }
+
+    fn part1(pairs: &Self::Parsed) -> Result<impl Display> {
+        Ok(covered_in_row(pairs, PART1_ROW).len())
+    }
+
+    fn part2(pairs: &Self::Parsed) -> Result<impl Display> {
+        tuning_frequency(pairs, &AaBox::new([0..=SEARCH_LIMIT, 0..=SEARCH_LIMIT]))
+    }
+}
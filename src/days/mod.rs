@@ -0,0 +1,118 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+use std::time::{Duration, Instant};
+
+use crate::{Result, Solution};
+
+/// A day's puzzle input once parsed, from which either part can be solved on its own.
+pub trait Puzzle: Send + Sync {
+    fn part1(&self) -> Result<String>;
+    fn part2(&self) -> Result<String>;
+}
+
+struct Parsed<S: Solution>(S::Parsed);
+
+impl<S: Solution> Puzzle for Parsed<S>
+where
+    S::Parsed: Send + Sync,
+{
+    fn part1(&self) -> Result<String> {
+        S::part1(&self.0).map(|answer| answer.to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        S::part2(&self.0).map(|answer| answer.to_string())
+    }
+}
+
+/// Parses the puzzle input for a day.
+pub type Solver = fn(&str) -> Result<Box<dyn Puzzle>>;
+
+/// The answers to both parts of a day, and how long it took to get them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub part1: String,
+    pub part2: String,
+    pub timings: Timings,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+pub const SOLVERS: [Solver; 15] = [
+    puzzle::<day01::Day01>,
+    puzzle::<day02::Day02>,
+    puzzle::<day03::Day03>,
+    puzzle::<day04::Day04>,
+    puzzle::<day05::Day05>,
+    puzzle::<day06::Day06>,
+    puzzle::<day07::Day07>,
+    puzzle::<day08::Day08>,
+    puzzle::<day09::Day09>,
+    puzzle::<day10::Day10>,
+    puzzle::<day11::Day11>,
+    puzzle::<day12::Day12>,
+    puzzle::<day13::Day13>,
+    puzzle::<day14::Day14>,
+    puzzle::<day15::Day15>,
+];
+
+pub fn puzzle<S: Solution + 'static>(input: &str) -> Result<Box<dyn Puzzle>>
+where
+    S::Parsed: Send + Sync,
+{
+    Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+}
+
+/// Parses the input and solves both parts one after the other, timing each of them.
+pub fn run(solver: Solver, input: &str) -> Result<Run> {
+    let (puzzle, parse) = timed(|| solver(input));
+    let puzzle = puzzle?;
+    let (part1, part1_time) = timed(|| puzzle.part1());
+    let (part2, part2_time) = timed(|| puzzle.part2());
+
+    Ok(Run {
+        part1: part1?,
+        part2: part2?,
+        timings: Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+    })
+}
+
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+pub fn solve<S: Solution + 'static>(input: &str) -> Result<(String, String)>
+where
+    S::Parsed: Send + Sync,
+{
+    run(puzzle::<S>, input).map(|run| (run.part1, run.part2))
+}
+
+pub fn solver(day: usize) -> Option<Solver> {
+    day.checked_sub(1).and_then(|i| SOLVERS.get(i)).copied()
+}
@@ -0,0 +1,114 @@
+use std::{fmt, io};
+
+use nom::Offset;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The puzzle input did not match what the parser expected. `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        expected: String,
+        /// The whole line the error is on, to point into when reporting it.
+        source_line: String,
+        /// What was being parsed when it failed, outermost first.
+        context: Vec<String>,
+    },
+    /// The input parsed, but describes a puzzle that can not be solved.
+    InvalidState(String),
+    /// A grid with no rows or columns, or with rows of different lengths.
+    InvalidShape(String),
+    /// An answers file that is not valid TOML, or does not hold answers to days and parts.
+    InvalidAnswers(String),
+    MissingInput {
+        input: String,
+        error: io::Error,
+    },
+    /// A benchmark report could not be written, or read back to compare against.
+    Report {
+        file: String,
+        error: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+impl Error {
+    /// A parse error for `at`, which has to be a slice of `input`.
+    pub fn parse(input: &str, at: &str, expected: impl Into<String>) -> Self {
+        let offset = input.offset(at);
+        let consumed = &input[..offset];
+        let line_start = consumed.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        Error::Parse {
+            line: consumed.matches('\n').count() + 1,
+            column: consumed[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            source_line: input[line_start..line_end].to_string(),
+            context: vec![],
+        }
+    }
+
+    pub fn invalid_state(reason: impl Into<String>) -> Self {
+        Error::InvalidState(reason.into())
+    }
+
+    /// Adds what was being parsed to a parse error, leaving any other error as it is.
+    pub fn with_context(mut self, labels: Vec<String>) -> Self {
+        if let Error::Parse { context, .. } = &mut self {
+            *context = labels;
+        }
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                expected,
+                source_line,
+                context,
+            } => {
+                write!(
+                    f,
+                    "parse error at line {}, column {}: expected {}",
+                    line, column, expected
+                )?;
+                if !context.is_empty() {
+                    write!(f, "\n  in {}", context.join(" → "))?;
+                }
+
+                let gutter = line.to_string().len();
+                write!(f, "\n  {} | {}", line, source_line)?;
+                write!(
+                    f,
+                    "\n  {:gutter$} | {:column$}^",
+                    "",
+                    "",
+                    column = column - 1
+                )
+            }
+            Error::InvalidState(reason) => write!(f, "invalid puzzle state: {}", reason),
+            Error::InvalidShape(reason) => write!(f, "invalid grid: {}", reason),
+            Error::InvalidAnswers(reason) => write!(f, "invalid answers: {}", reason),
+            Error::MissingInput { input, error } => {
+                write!(f, "missing input {}: {}", input, error)
+            }
+            Error::Report { file, error } => write!(f, "benchmark report {}: {}", file, error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::MissingInput { error, .. } => Some(error),
+            Error::Report { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
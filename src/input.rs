@@ -0,0 +1,110 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::{Error, Result};
+
+/// Environment variable that overrides where puzzle inputs are looked up.
+pub const INPUTS_DIR_VAR: &str = "AOC22_INPUTS";
+pub const DEFAULT_INPUTS_DIR: &str = "data";
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A single input file, used as is whichever day is being solved.
+    File(PathBuf),
+    Stdin,
+    /// A directory holding one `dayNN.txt` file per day.
+    Directory(PathBuf),
+}
+
+impl InputSource {
+    /// `-` stands for stdin, anything else is a path to the input file.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(arg.into())
+        }
+    }
+
+    pub fn read(&self, day: usize) -> Result<String> {
+        match self {
+            InputSource::File(path) => read_file(path.clone()),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|error| Error::MissingInput {
+                        input: "from stdin".to_string(),
+                        error,
+                    })?;
+                Ok(input)
+            }
+            InputSource::Directory(dir) => read_file(dir.join(format!("day{:02}.txt", day))),
+        }
+    }
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Directory(inputs_dir())
+    }
+}
+
+pub fn inputs_dir() -> PathBuf {
+    env::var_os(INPUTS_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| DEFAULT_INPUTS_DIR.into())
+}
+
+fn read_file(path: PathBuf) -> Result<String> {
+    fs::read_to_string(&path).map_err(|error| Error::MissingInput {
+        input: path.display().to_string(),
+        error,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn inputs_come_from_an_argument() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+        assert_eq!(
+            InputSource::File("day01.txt".into()),
+            InputSource::from_arg("day01.txt")
+        );
+    }
+
+    #[test]
+    fn directories_hold_an_input_for_each_day() {
+        let dir = env::temp_dir().join("aoc22-input-directory");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day05.txt"), "5\n").unwrap();
+        let _ = fs::remove_file(dir.join("day06.txt"));
+        let inputs = InputSource::Directory(dir.clone());
+
+        assert_eq!("5\n", inputs.read(5).unwrap());
+        assert_eq!(
+            "5\n",
+            InputSource::File(dir.join("day05.txt")).read(6).unwrap()
+        );
+
+        let missing = dir.join("day06.txt").display().to_string();
+        let error = inputs.read(6).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with(&format!("missing input {}: ", missing)));
+        match error {
+            Error::MissingInput { input, error } => {
+                assert_eq!((missing, io::ErrorKind::NotFound), (input, error.kind()))
+            }
+            error => panic!("expected a missing input, got {}", error),
+        }
+    }
+}
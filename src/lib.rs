@@ -1,5 +1,31 @@
+use std::fmt::Display;
+
+pub mod answers;
+pub mod bench;
+pub mod days;
+mod error;
+pub mod input;
 pub mod itertools;
 pub mod math;
 pub mod matrix;
 pub mod parsers;
 pub mod range;
+pub mod runner;
+pub mod search;
+pub mod sparse;
+
+pub use error::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A day's puzzle: how to read its input and how to answer each of its two parts.
+pub trait Solution {
+    /// The puzzle input once parsed, shared by both parts.
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+
+    fn part1(parsed: &Self::Parsed) -> Result<impl Display>;
+
+    fn part2(parsed: &Self::Parsed) -> Result<impl Display>;
+}
@@ -1,3 +1,472 @@
+use std::{env, ops::RangeInclusive, path::PathBuf, process, thread, time::Duration};
+
+use aoc22::answers::{Answers, Status, DEFAULT_ANSWERS_FILE};
+use aoc22::bench::{self, Report};
+use aoc22::runner::{self, Solved};
+use aoc22::{days, input::InputSource};
+use serde::Serialize;
+
+const USAGE: &str =
+    "usage: aoc22 [all | DAY | FROM-TO] [--part 1|2] [--input FILE | -] [--inputs DIR]
+             [--answers FILE] [--format table|json|csv] [--jobs N]
+             [--bench ITERATIONS [--warmup N] [--save FILE] [--baseline FILE]]
+
+With --bench, both parts are always solved and timed, --part only picks the rows shown.";
+
+#[derive(Debug)]
+struct Args {
+    days: RangeInclusive<usize>,
+    part: Option<usize>,
+    input: InputSource,
+    /// Known answers to check against, by default the answers file in the inputs directory.
+    answers: Option<PathBuf>,
+    format: Format,
+    /// How many days, or parts of days, to solve at the same time.
+    jobs: usize,
+    bench: Option<Bench>,
+}
+
+/// A part's answer, or the error that stopped the run, as written for `--format json`.
+#[derive(Debug, Serialize)]
+struct Record {
+    day: usize,
+    part: usize,
+    answer: String,
+    elapsed_ns: u64,
+    status: String,
+    expected: Option<String>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Table,
+    /// A single array with an object for each part solved.
+    Json,
+    Csv,
+}
+
+#[derive(Debug)]
+struct Bench {
+    iterations: usize,
+    warmup: usize,
+    /// Where to write the results as JSON.
+    save: Option<PathBuf>,
+    /// A report saved by an earlier run, to compare against.
+    baseline: Option<PathBuf>,
+}
+
+fn parse_day(day: &str) -> Result<usize, String> {
+    match day.parse() {
+        Ok(day) if days::solver(day).is_some() => Ok(day),
+        _ => Err(format!("no solution for day '{}'", day)),
+    }
+}
+
+fn parse_days(selection: &str) -> Result<RangeInclusive<usize>, String> {
+    if selection == "all" {
+        return Ok(1..=days::SOLVERS.len());
+    }
+
+    match selection.split_once('-') {
+        Some((from, to)) => {
+            let (from, to) = (parse_day(from)?, parse_day(to)?);
+            if from > to {
+                return Err(format!(
+                    "the range starts at day {} after it ends at day {}",
+                    from, to
+                ));
+            }
+            Ok(from..=to)
+        }
+        None => parse_day(selection).map(|day| day..=day),
+    }
+}
+
+fn parse_count(flag: &str, count: Option<String>) -> Result<usize, String> {
+    count
+        .and_then(|count| count.parse().ok())
+        .ok_or_else(|| format!("{} expects a number", flag))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut days = 1..=days::SOLVERS.len();
+    let mut part = None;
+    let mut input = InputSource::default();
+    let mut answers = None;
+    let mut format = None;
+    let mut jobs = None;
+    let mut iterations = None;
+    let mut warmup = None;
+    let mut save = None;
+    let mut baseline = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--part" => {
+                part = match args.next().as_deref() {
+                    Some("1") => Some(1),
+                    Some("2") => Some(2),
+                    _ => return Err("--part expects 1 or 2".to_string()),
+                }
+            }
+            "-i" | "--input" => match args.next() {
+                Some(file) => input = InputSource::from_arg(&file),
+                None => return Err("--input expects a file, or - for stdin".to_string()),
+            },
+            "--inputs" => match args.next() {
+                Some(dir) => input = InputSource::Directory(dir.into()),
+                None => return Err("--inputs expects a directory".to_string()),
+            },
+            "--answers" => match args.next() {
+                Some(file) => answers = Some(file.into()),
+                None => return Err("--answers expects a file".to_string()),
+            },
+            "--format" => {
+                format = match args.next().as_deref() {
+                    Some("table") => Some(Format::Table),
+                    Some("json") => Some(Format::Json),
+                    Some("csv") => Some(Format::Csv),
+                    _ => return Err("--format expects table, json or csv".to_string()),
+                }
+            }
+            "-j" | "--jobs" => match parse_count(&arg, args.next())? {
+                0 => return Err("--jobs needs at least one thread".to_string()),
+                n => jobs = Some(n),
+            },
+            "--bench" => iterations = Some(parse_count(&arg, args.next())?),
+            "--warmup" => warmup = Some(parse_count(&arg, args.next())?),
+            "--save" => match args.next() {
+                Some(file) => save = Some(file.into()),
+                None => return Err("--save expects a file".to_string()),
+            },
+            "--baseline" => match args.next() {
+                Some(file) => baseline = Some(file.into()),
+                None => return Err("--baseline expects a file".to_string()),
+            },
+            option if option.starts_with('-') => return Err(format!("unknown option {}", option)),
+            selection => days = parse_days(selection)?,
+        }
+    }
+
+    let bench = match iterations {
+        Some(0) => return Err("--bench needs at least one iteration".to_string()),
+        Some(iterations) => Some(Bench {
+            iterations,
+            warmup: warmup.unwrap_or(1),
+            save,
+            baseline,
+        }),
+        None if warmup.is_some() || save.is_some() || baseline.is_some() => {
+            return Err("--warmup, --save and --baseline can only be used with --bench".to_string())
+        }
+        None => None,
+    };
+
+    if bench.is_some() && format.is_some() {
+        return Err("--format can not be used with --bench, see --save instead".to_string());
+    }
+    // Days would slow each other down, and make the timings meaningless
+    if bench.is_some() && jobs.is_some() {
+        return Err(
+            "--jobs can not be used with --bench, which solves one day at a time".to_string(),
+        );
+    }
+
+    if !matches!(input, InputSource::Directory(_)) && days.start() != days.end() {
+        return Err("--input can only be used when solving a single day".to_string());
+    }
+
+    // Answers to the real inputs say nothing about an example passed with --input
+    if let (None, InputSource::Directory(dir)) = (&answers, &input) {
+        answers = Some(dir.join(DEFAULT_ANSWERS_FILE)).filter(|file| file.exists());
+    }
+
+    Ok(Args {
+        days,
+        part,
+        input,
+        answers,
+        format: format.unwrap_or(Format::Table),
+        jobs: jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, usize::from)),
+        bench,
+    })
+}
+
+fn print_row(day: usize, part: usize, status: &Status, answer: &str) {
+    let mut lines = answer.lines();
+    println!(
+        "{:>3} | {:>4} | {:<8} | {}",
+        format!("{:02}", day),
+        part,
+        status,
+        lines.next().unwrap_or_default()
+    );
+    for line in lines {
+        println!("{:>3} | {:>4} | {:<8} | {}", "", "", "", line);
+    }
+    if let Status::Fail { expected } = status {
+        for (i, line) in expected.lines().enumerate() {
+            let label = if i == 0 { "expected" } else { "" };
+            println!("{:>3} | {:>4} | {:>8} | {}", "", "", label, line);
+        }
+    }
+}
+
+/// Quotes a CSV field if it has to be.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn exit_on_error<T>(day: usize, result: aoc22::Result<T>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("day {:02}: {}", day, e);
+        process::exit(1);
+    })
+}
+
+/// Solves the days and prints their answers, returning whether they all matched the known ones.
+fn solve(args: &Args) -> bool {
+    let answers = match &args.answers {
+        Some(file) => Answers::read(file).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        }),
+        None => Answers::default(),
+    };
+    let mut failed = false;
+    let mut records = vec![];
+
+    match args.format {
+        Format::Table => {
+            println!("Day | Part | Status   | Answer");
+            println!("----+------+----------+-------");
+        }
+        Format::Csv => println!("day,part,answer,elapsed_ns,status,expected,error"),
+        Format::Json => {}
+    }
+
+    let days = args.days.clone().collect::<Vec<_>>();
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let solved = runner::solve_days(&days, &parts, &args.input, args.jobs, |solved| {
+        let Solved {
+            day,
+            part,
+            answer,
+            elapsed,
+        } = solved;
+        let status = answers.check(day, part, &answer);
+        let expected = match &status {
+            Status::Fail { expected } => Some(expected.as_str()),
+            _ => None,
+        };
+        failed |= expected.is_some();
+
+        match args.format {
+            Format::Table => print_row(day, part, &status, &answer),
+            Format::Csv => println!(
+                "{},{},{},{},{},{},",
+                day,
+                part,
+                csv_field(&answer),
+                elapsed.as_nanos(),
+                status,
+                csv_field(expected.unwrap_or_default())
+            ),
+            Format::Json => records.push(Record {
+                day,
+                part,
+                answer,
+                elapsed_ns: elapsed.as_nanos() as u64,
+                status: status.to_string(),
+                expected: expected.map(str::to_string),
+                error: None,
+            }),
+        }
+    });
+
+    // The answers before the error are still worth having, along with what went wrong
+    if let Err((day, part, e)) = &solved {
+        match args.format {
+            Format::Table => {}
+            Format::Csv => println!("{},{},,,error,,{}", day, part, csv_field(&e.to_string())),
+            Format::Json => records.push(Record {
+                day: *day,
+                part: *part,
+                answer: String::new(),
+                elapsed_ns: 0,
+                status: "error".to_string(),
+                expected: None,
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+    if args.format == Format::Json {
+        println!("{}", serde_json::to_string(&records).unwrap());
+    }
+    if let Err((day, _, e)) = solved {
+        eprintln!("day {:02}: {}", day, e);
+        process::exit(1);
+    }
+
+    !failed
+}
+
+/// How much slower `after` is than `before`, or nothing if it can not be told from a baseline
+/// that took no time at all.
+fn change(before: Duration, after: Duration) -> String {
+    if before.is_zero() {
+        return String::new();
+    }
+
+    let before = before.as_secs_f64();
+    format!("{:+.1}%", (after.as_secs_f64() - before) / before * 100.0)
+}
+
+fn benchmark(args: &Args, bench: &Bench) {
+    let baseline = bench.baseline.as_ref().map(|file| {
+        Report::read(file).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        })
+    });
+    let mut report = Report {
+        warmup: bench.warmup,
+        iterations: bench.iterations,
+        days: vec![],
+    };
+
+    println!("Day | Phase |        Min |     Median |       Mean | Median vs baseline");
+    println!("----+-------+------------+------------+------------+-------------------");
+
+    for day in args.days.clone() {
+        let solve = days::solver(day).unwrap();
+        let input = exit_on_error(day, args.input.read(day));
+        let result = exit_on_error(
+            day,
+            bench::bench(day, solve, &input, bench.warmup, bench.iterations),
+        );
+        let previous = baseline.as_ref().and_then(|baseline| baseline.day(day));
+
+        for (i, (phase, stats)) in result.phases().into_iter().enumerate() {
+            if args.part.is_some_and(|part| i != 0 && i != part) {
+                continue;
+            }
+
+            let change = previous
+                .map(|previous| change(previous.phases()[i].1.median, stats.median))
+                .unwrap_or_default();
+            println!(
+                "{:>3} | {:>5} | {:>10.2?} | {:>10.2?} | {:>10.2?} | {}",
+                format!("{:02}", day),
+                phase,
+                stats.min,
+                stats.median,
+                stats.mean,
+                change
+            );
+        }
+
+        report.days.push(result);
+    }
+
+    if let Some(file) = &bench.save {
+        if let Err(e) = report.write(file) {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+
+    match &args.bench {
+        Some(bench) => benchmark(&args, bench),
+        None => {
+            if !solve(&args) {
+                process::exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn parse(args: &str) -> Result<Args, String> {
+        parse_args(args.split_whitespace().map(str::to_string))
+    }
+
+    fn selected(args: &str) -> Result<RangeInclusive<usize>, String> {
+        parse(args).map(|args| args.days)
+    }
+
+    #[test]
+    fn days_are_selected_one_at_a_time_or_as_a_range() {
+        assert_eq!(Ok(1..=days::SOLVERS.len()), selected(""));
+        assert_eq!(Ok(1..=days::SOLVERS.len()), selected("all"));
+        assert_eq!(Ok(5..=5), selected("5"));
+        assert_eq!(Ok(2..=5), selected("2-5"));
+        assert_eq!(Ok(3..=3), selected("3-3"));
+
+        assert_eq!(
+            Err("the range starts at day 3 after it ends at day 1".to_string()),
+            selected("3-1")
+        );
+        assert_eq!(Err("no solution for day '0'".to_string()), selected("0"));
+        assert_eq!(Err("no solution for day ''".to_string()), selected("3-"));
+    }
+
+    #[test]
+    fn conflicting_or_unknown_options_are_rejected() {
+        let error = |args| parse(args).unwrap_err();
+
+        assert_eq!("unknown option --bogus", error("--bogus"));
+        assert_eq!("unknown option --fromat", error("--fromat json"));
+        assert_eq!(
+            "--input can only be used when solving a single day",
+            error("1-3 --input day01.txt")
+        );
+        assert_eq!(
+            "--format can not be used with --bench, see --save instead",
+            error("1 --bench 3 --format json")
+        );
+        assert_eq!(
+            "--jobs can not be used with --bench, which solves one day at a time",
+            error("1 --bench 3 --jobs 2")
+        );
+        assert_eq!(
+            "--warmup, --save and --baseline can only be used with --bench",
+            error("1 --warmup 2")
+        );
+
+        assert!(parse("3 --input day03.txt").is_ok());
+        assert!(parse("--input - 4").is_ok());
+    }
+
+    #[test]
+    fn changes_compare_to_the_baseline() {
+        let ms = Duration::from_millis;
+
+        assert_eq!("+50.0%", change(ms(2), ms(3)));
+        assert_eq!("-25.0%", change(ms(4), ms(3)));
+        assert_eq!("", change(Duration::ZERO, ms(3)));
+        assert_eq!("", change(Duration::ZERO, Duration::ZERO));
+    }
 }
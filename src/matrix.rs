@@ -1,4 +1,9 @@
-use std::ops;
+use std::collections::HashSet;
+use std::{fmt, ops};
+
+use num::ToPrimitive;
+
+use crate::{Error, Result};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Direction {
@@ -6,8 +11,101 @@ pub enum Direction {
     Right,
     Top,
     Bottom,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Left,
+        Direction::Right,
+        Direction::Top,
+        Direction::Bottom,
+    ];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::Left,
+        Direction::Right,
+        Direction::Top,
+        Direction::Bottom,
+        Direction::TopLeft,
+        Direction::TopRight,
+        Direction::BottomLeft,
+        Direction::BottomRight,
+    ];
+
+    /// The step one element in this direction takes, as `(rows, cols)`.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+            Direction::Top => (-1, 0),
+            Direction::Bottom => (1, 0),
+            Direction::TopLeft => (-1, -1),
+            Direction::TopRight => (-1, 1),
+            Direction::BottomLeft => (1, -1),
+            Direction::BottomRight => (1, 1),
+        }
+    }
+}
+
+/// Which elements count as next to each other when finding regions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Left, right, above and below.
+    Four,
+    /// Diagonals too.
+    Eight,
+}
+
+impl Connectivity {
+    pub fn directions(self) -> &'static [Direction] {
+        match self {
+            Connectivity::Four => &Direction::ORTHOGONAL,
+            Connectivity::Eight => &Direction::ALL,
+        }
+    }
+}
+
+/// A connected set of elements, found by [`Matrix::flood_fill`] or [`Matrix::label_components`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub id: usize,
+    /// In the order they were found, starting from the cell the fill started at. That is the first
+    /// cell of the region in reading order for [`Matrix::label_components`].
+    pub cells: Vec<(usize, usize)>,
+    /// The corners of the smallest rectangle around the region, both included.
+    pub top_left: (usize, usize),
+    pub bottom_right: (usize, usize),
+}
+
+impl Region {
+    pub fn size(&self) -> usize {
+        self.cells.len()
+    }
 }
 
+/// What a stencil does with the offsets that fall outside the matrix.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Boundary<'a, T> {
+    /// Leaves them out.
+    Clip,
+    /// Wraps them around to the other side, as if the matrix were a torus.
+    Wrap,
+    /// Uses the given element in their place.
+    Pad(&'a T),
+}
+
+impl<T> Clone for Boundary<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Boundary<'_, T> {}
+
 type Index = (usize, usize);
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -33,8 +131,10 @@ impl From<(usize, usize)> for InnerIndex {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A grid of `rows` by `cols` elements, stored row after row in a single `Vec`.
 pub struct Matrix<T> {
-    data: Vec<Vec<T>>,
+    data: Vec<T>,
     rows: usize,
     cols: usize,
 }
@@ -45,11 +145,63 @@ impl<T> Matrix<T> {
         T: Default + Clone,
     {
         Matrix {
-            data: vec![vec![T::default(); cols]; rows],
+            data: vec![T::default(); rows * cols],
             rows,
             cols,
         }
     }
+
+    /// Parses a grid with a row on each line and an element for each character, as mapped by
+    /// `cell`. Characters it maps to `None` are reported as not being the `expected` element.
+    pub fn from_grid_str(
+        input: &str,
+        expected: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        Self::from_grid_str_with_markers(input, &[], expected, cell).map(|(matrix, _)| matrix)
+    }
+
+    /// Like `from_grid_str`, also locating every occurrence of the `markers` characters, in
+    /// reading order.
+    pub fn from_grid_str_with_markers(
+        input: &str,
+        markers: &[char],
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<(Self, Vec<(char, Index)>)> {
+        let mut data = Vec::with_capacity(input.len());
+        let mut located = Vec::new();
+        let mut rows = 0;
+        let mut cols = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let mut len = 0;
+            for (j, (at, c)) in line.char_indices().enumerate() {
+                data.push(cell(c).ok_or_else(|| Error::parse(input, &line[at..], expected))?);
+                if markers.contains(&c) {
+                    located.push((c, (i, j)));
+                }
+                len += 1;
+            }
+            match cols {
+                Some(cols) if cols != len => {
+                    return Err(Error::parse(
+                        input,
+                        line,
+                        format!("a row of {} cells", cols),
+                    ))
+                }
+                _ => cols = Some(len),
+            }
+            rows += 1;
+        }
+
+        match cols {
+            Some(cols) if cols > 0 => Ok((Matrix { data, rows, cols }, located)),
+            _ => Err(Error::parse(input, input, "a row of cells")),
+        }
+    }
+
     pub fn number_of_rows(&self) -> usize {
         self.rows
     }
@@ -59,26 +211,53 @@ impl<T> Matrix<T> {
     }
 
     pub fn get(&self, (i, j): Index) -> Option<&T> {
-        if i <= self.rows && j <= self.cols {
-            Some(&self.data[i][j])
+        if i < self.rows && j < self.cols {
+            Some(&self.data[i * self.cols + j])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (i, j): Index) -> Option<&mut T> {
+        if i < self.rows && j < self.cols {
+            Some(&mut self.data[i * self.cols + j])
         } else {
             None
         }
     }
 
-    pub fn elements_to_right(&self, idx: Index) -> ElementsInDirection<T> {
+    /// All the elements, row by row.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// All the elements, row by row.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        assert!(i < self.rows, "row {} out of {}", i, self.rows);
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.rows).map(|i| self.row(i))
+    }
+
+    pub fn elements_to_right(&self, idx: Index) -> ElementsInDirection<'_, T> {
         self.elements_in_direction(idx, Direction::Right)
     }
 
-    pub fn elements_to_left(&self, idx: Index) -> ElementsInDirection<T> {
+    pub fn elements_to_left(&self, idx: Index) -> ElementsInDirection<'_, T> {
         self.elements_in_direction(idx, Direction::Left)
     }
 
-    pub fn elements_to_top(&self, idx: Index) -> ElementsInDirection<T> {
+    pub fn elements_to_top(&self, idx: Index) -> ElementsInDirection<'_, T> {
         self.elements_in_direction(idx, Direction::Top)
     }
 
-    pub fn elements_to_bottom(&self, idx: Index) -> ElementsInDirection<T> {
+    pub fn elements_to_bottom(&self, idx: Index) -> ElementsInDirection<'_, T> {
         self.elements_in_direction(idx, Direction::Bottom)
     }
 
@@ -87,91 +266,542 @@ impl<T> Matrix<T> {
         current_index: InnerIndex,
         direction: Direction,
     ) -> Option<InnerIndex> {
-        match direction {
-            Direction::Left => {
-                if current_index.col() == 0 {
-                    None
-                } else {
-                    Some(InnerIndex(current_index.row(), current_index.col() - 1))
-                }
-            }
-            Direction::Right => {
-                if current_index.col() >= self.number_of_cols() - 1 {
-                    None
-                } else {
-                    Some(InnerIndex(current_index.row(), current_index.col() + 1))
-                }
-            }
-            Direction::Top => {
-                if current_index.row() == 0 {
-                    None
-                } else {
-                    Some(InnerIndex(current_index.row() - 1, current_index.col()))
+        self.offset_index(current_index.into(), direction.offset(), false)
+            .map(InnerIndex::from)
+    }
+
+    /// The index `offset` away from `index`, if it is inside the matrix or `wrap` is set.
+    pub fn offset_index(&self, index: Index, offset: (isize, isize), wrap: bool) -> Option<Index> {
+        offset_within((self.rows, self.cols), index, offset, wrap)
+    }
+
+    /// The indices left, right, above and below `index`.
+    pub fn neighbouring_indices(&self, index: Index) -> impl Iterator<Item = Index> + '_ {
+        self.indices_in_directions(index, &Direction::ORTHOGONAL)
+    }
+
+    /// The indices of all eight elements around `index`, diagonals included.
+    pub fn surrounding_indices(&self, index: Index) -> impl Iterator<Item = Index> + '_ {
+        self.indices_in_directions(index, &Direction::ALL)
+    }
+
+    fn indices_in_directions(
+        &self,
+        index: Index,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Index> + '_ {
+        directions
+            .iter()
+            .filter_map(move |d| self.next_in_direction(index.into(), *d))
+            .map(|i| i.into())
+    }
+
+    /// The elements at each of `offsets` from `index`, in the same order.
+    pub fn stencil<'a>(
+        &'a self,
+        index: Index,
+        offsets: &'a [(isize, isize)],
+        boundary: Boundary<'a, T>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        offsets.iter().filter_map(move |&offset| match boundary {
+            Boundary::Clip => self.offset_index(index, offset, false).map(|i| &self[i]),
+            Boundary::Wrap => self.offset_index(index, offset, true).map(|i| &self[i]),
+            Boundary::Pad(pad) => Some(
+                self.offset_index(index, offset, false)
+                    .map_or(pad, |i| &self[i]),
+            ),
+        })
+    }
+
+    /// The region of elements `predicate` accepts that `start` is in, if it accepts `start`.
+    pub fn flood_fill(
+        &self,
+        start: Index,
+        predicate: impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+    ) -> Option<Region> {
+        let accepted = self.get(start).is_some_and(&predicate);
+        let mut labels = Matrix::new((self.rows, self.cols));
+        accepted.then(|| self.fill(start, 0, &predicate, connectivity, &mut labels))
+    }
+
+    /// Splits the elements `predicate` accepts into regions, numbered in the order of their first
+    /// cell in reading order. The labels give the region each element is in, if any.
+    pub fn label_components(
+        &self,
+        predicate: impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+    ) -> (Matrix<Option<usize>>, Vec<Region>) {
+        let mut labels: Matrix<Option<usize>> = Matrix::new((self.rows, self.cols));
+        let mut regions = Vec::new();
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                if labels[(i, j)].is_none() && predicate(&self[(i, j)]) {
+                    let region =
+                        self.fill((i, j), regions.len(), &predicate, connectivity, &mut labels);
+                    regions.push(region);
                 }
             }
-            Direction::Bottom => {
-                if current_index.row() >= self.number_of_rows() - 1 {
-                    None
-                } else {
-                    Some(InnerIndex(current_index.row() + 1, current_index.col()))
+        }
+        (labels, regions)
+    }
+
+    fn fill(
+        &self,
+        start: Index,
+        id: usize,
+        predicate: &impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+        labels: &mut Matrix<Option<usize>>,
+    ) -> Region {
+        labels[start] = Some(id);
+        let mut cells = vec![start];
+        let mut stack = vec![start];
+        while let Some(index) = stack.pop() {
+            for next in self.indices_in_directions(index, connectivity.directions()) {
+                if labels[next].is_none() && predicate(&self[next]) {
+                    labels[next] = Some(id);
+                    cells.push(next);
+                    stack.push(next);
                 }
             }
         }
+
+        let (rows, cols): (Vec<_>, Vec<_>) = cells.iter().copied().unzip();
+        Region {
+            id,
+            top_left: (*rows.iter().min().unwrap(), *cols.iter().min().unwrap()),
+            bottom_right: (*rows.iter().max().unwrap(), *cols.iter().max().unwrap()),
+            cells,
+        }
     }
 
-    pub fn neighbouring_indices(&self, index: Index) -> impl Iterator<Item = Index> + '_ {
-        [
-            Direction::Left,
-            Direction::Right,
-            Direction::Top,
-            Direction::Bottom,
-        ]
-        .iter()
-        .filter_map(move |d| self.next_in_direction(index.into(), *d))
-        .map(|i| i.into())
+    /// Formats each element with `cell`, for printing with `{}`. Cells are padded to the widest
+    /// of them, and separated by a space unless they are all one character wide.
+    pub fn render<F: Fn(&T) -> String>(&self, cell: F) -> Render<'_, T, F> {
+        Render {
+            matrix: self,
+            cell,
+            rulers: false,
+            highlighted: HashSet::new(),
+            heat: None,
+        }
     }
 
     pub fn elements_in_direction(
         &self,
         idx: Index,
         direction: Direction,
-    ) -> ElementsInDirection<T> {
+    ) -> ElementsInDirection<'_, T> {
+        self.view(0..self.rows, 0..self.cols)
+            .elements_in_direction(idx, direction)
+    }
+
+    /// Borrows the elements in `rows` and `cols`, indexed from the top left of the view.
+    pub fn view(&self, rows: ops::Range<usize>, cols: ops::Range<usize>) -> MatrixView<'_, T> {
+        assert!(
+            rows.start <= rows.end && rows.end <= self.rows,
+            "rows {:?} out of {}",
+            rows,
+            self.rows
+        );
+        assert!(
+            cols.start <= cols.end && cols.end <= self.cols,
+            "columns {:?} out of {}",
+            cols,
+            self.cols
+        );
+        MatrixView {
+            matrix: self,
+            origin: (rows.start, cols.start),
+            rows: rows.len(),
+            cols: cols.len(),
+        }
+    }
+
+    /// Swaps rows for columns.
+    pub fn transpose(self) -> Self {
+        let (rows, cols) = (self.rows, self.cols);
+        self.rearrange((cols, rows), |(i, j)| (j, i))
+    }
+
+    /// Turns the matrix a quarter clockwise.
+    pub fn rotate_cw(self) -> Self {
+        let (rows, cols) = (self.rows, self.cols);
+        self.rearrange((cols, rows), |(i, j)| (rows - 1 - j, i))
+    }
+
+    /// Turns the matrix a quarter counterclockwise.
+    pub fn rotate_ccw(self) -> Self {
+        let (rows, cols) = (self.rows, self.cols);
+        self.rearrange((cols, rows), |(i, j)| (j, cols - 1 - i))
+    }
+
+    /// Mirrors the matrix left to right.
+    pub fn flip_horizontal(self) -> Self {
+        let (rows, cols) = (self.rows, self.cols);
+        self.rearrange((rows, cols), |(i, j)| (i, cols - 1 - j))
+    }
+
+    /// Mirrors the matrix top to bottom.
+    pub fn flip_vertical(self) -> Self {
+        let (rows, cols) = (self.rows, self.cols);
+        self.rearrange((rows, cols), |(i, j)| (rows - 1 - i, j))
+    }
+
+    /// Moves the elements into a `rows` by `cols` matrix, taking each from the index `source`
+    /// gives for it, without cloning them.
+    fn rearrange(self, (rows, cols): (usize, usize), source: impl Fn(Index) -> Index) -> Self {
+        let old_cols = self.cols;
+        let mut elements = self.data.into_iter().map(Some).collect::<Vec<_>>();
+        let data = (0..rows)
+            .flat_map(|i| (0..cols).map(move |j| (i, j)))
+            .map(|index| {
+                let (i, j) = source(index);
+                elements[i * old_cols + j].take().unwrap()
+            })
+            .collect();
+
+        Matrix { data, rows, cols }
+    }
+}
+
+/// A rectangular part of a [`Matrix`], as borrowed by [`Matrix::view`].
+pub struct MatrixView<'a, T> {
+    matrix: &'a Matrix<T>,
+    origin: Index,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+impl<'a, T> MatrixView<'a, T> {
+    pub fn number_of_rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn number_of_cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, (i, j): Index) -> Option<&'a T> {
+        if i < self.rows && j < self.cols {
+            self.matrix.get((self.origin.0 + i, self.origin.1 + j))
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, i: usize) -> &'a [T] {
+        assert!(i < self.rows, "row {} out of {}", i, self.rows);
+        let start = (self.origin.0 + i) * self.matrix.cols + self.origin.1;
+        &self.matrix.data[start..start + self.cols]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + 'a {
+        let view = *self;
+        (0..self.rows).map(move |i| view.row(i))
+    }
+
+    /// A view of part of this view, with `rows` and `cols` counted from its top left.
+    pub fn view(&self, rows: ops::Range<usize>, cols: ops::Range<usize>) -> MatrixView<'a, T> {
+        assert!(
+            rows.start <= rows.end && rows.end <= self.rows,
+            "rows {:?} out of {}",
+            rows,
+            self.rows
+        );
+        assert!(
+            cols.start <= cols.end && cols.end <= self.cols,
+            "columns {:?} out of {}",
+            cols,
+            self.cols
+        );
+        MatrixView {
+            matrix: self.matrix,
+            origin: (self.origin.0 + rows.start, self.origin.1 + cols.start),
+            rows: rows.len(),
+            cols: cols.len(),
+        }
+    }
+
+    /// Copies the viewed elements into a matrix of their own.
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix {
+            data: self.rows().flatten().cloned().collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    fn next_in_direction(
+        &self,
+        current_index: InnerIndex,
+        direction: Direction,
+    ) -> Option<InnerIndex> {
+        self.offset_index(current_index.into(), direction.offset(), false)
+            .map(InnerIndex::from)
+    }
+
+    /// The index `offset` away from `index`, if it is inside the view or `wrap` is set.
+    pub fn offset_index(&self, index: Index, offset: (isize, isize), wrap: bool) -> Option<Index> {
+        offset_within((self.rows, self.cols), index, offset, wrap)
+    }
+
+    /// The indices left, right, above and below `index`.
+    pub fn neighbouring_indices(&self, index: Index) -> impl Iterator<Item = Index> + 'a {
+        self.indices_in_directions(index, &Direction::ORTHOGONAL)
+    }
+
+    /// The indices of all eight elements around `index`, diagonals included.
+    pub fn surrounding_indices(&self, index: Index) -> impl Iterator<Item = Index> + 'a {
+        self.indices_in_directions(index, &Direction::ALL)
+    }
+
+    fn indices_in_directions(
+        &self,
+        index: Index,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Index> + 'a {
+        let view = *self;
+        directions
+            .iter()
+            .filter_map(move |d| view.next_in_direction(index.into(), *d))
+            .map(|i| i.into())
+    }
+
+    pub fn elements_to_right(&self, idx: Index) -> ElementsInDirection<'a, T> {
+        self.elements_in_direction(idx, Direction::Right)
+    }
+
+    pub fn elements_to_left(&self, idx: Index) -> ElementsInDirection<'a, T> {
+        self.elements_in_direction(idx, Direction::Left)
+    }
+
+    pub fn elements_to_top(&self, idx: Index) -> ElementsInDirection<'a, T> {
+        self.elements_in_direction(idx, Direction::Top)
+    }
+
+    pub fn elements_to_bottom(&self, idx: Index) -> ElementsInDirection<'a, T> {
+        self.elements_in_direction(idx, Direction::Bottom)
+    }
+
+    pub fn elements_in_direction(
+        &self,
+        idx: Index,
+        direction: Direction,
+    ) -> ElementsInDirection<'a, T> {
         let idx = idx.into();
         let current_index = self.next_in_direction(idx, direction);
 
         ElementsInDirection {
-            data: self,
+            data: *self,
             current_index,
             direction,
         }
     }
 }
 
-impl<T> From<Vec<Vec<T>>> for Matrix<T> {
-    fn from(data: Vec<Vec<T>>) -> Self {
+impl<T> ops::Index<Index> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (i, j): Index) -> &Self::Output {
+        &self.row(i)[j]
+    }
+}
+
+fn offset_within(
+    (rows, cols): (usize, usize),
+    (i, j): Index,
+    (di, dj): (isize, isize),
+    wrap: bool,
+) -> Option<Index> {
+    // There is nothing to wrap around to
+    if rows == 0 || cols == 0 {
+        None
+    } else if wrap {
+        let wrapped =
+            |k: usize, dk: isize, len: usize| (k as isize + dk).rem_euclid(len as isize) as usize;
+        Some((wrapped(i, di, rows), wrapped(j, dj, cols)))
+    } else {
+        let i = i.checked_add_signed(di).filter(|&i| i < rows)?;
+        let j = j.checked_add_signed(dj).filter(|&j| j < cols)?;
+        Some((i, j))
+    }
+}
+
+/// Fails unless there is at least one row, and all rows have the same, non-zero, length.
+impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = Error;
+
+    fn try_from(data: Vec<Vec<T>>) -> Result<Self> {
         let rows = data.len();
-        let cols = data[0].len();
+        let cols = match data.first() {
+            None => return Err(Error::InvalidShape("there are no rows".to_string())),
+            Some(first) if first.is_empty() => {
+                return Err(Error::InvalidShape("there are no columns".to_string()))
+            }
+            Some(first) => first.len(),
+        };
+        if let Some((i, row)) = data.iter().enumerate().find(|(_, row)| row.len() != cols) {
+            return Err(Error::InvalidShape(format!(
+                "row {} has {} columns instead of {}",
+                i,
+                row.len(),
+                cols
+            )));
+        }
 
-        Matrix { data, rows, cols }
+        let data = data.into_iter().flatten().collect();
+        Ok(Matrix { data, rows, cols })
     }
 }
 
 impl<T> ops::Index<Index> for Matrix<T> {
     type Output = T;
 
-    fn index(&self, index: Index) -> &Self::Output {
-        &self.data[index.0][index.1]
+    fn index(&self, (i, j): Index) -> &Self::Output {
+        assert!(j < self.cols, "column {} out of {}", j, self.cols);
+        &self.data[i * self.cols + j]
     }
 }
 
 impl<T> ops::IndexMut<Index> for Matrix<T> {
-    fn index_mut(&mut self, index: Index) -> &mut Self::Output {
-        &mut self.data[index.0][index.1]
+    fn index_mut(&mut self, (i, j): Index) -> &mut Self::Output {
+        assert!(j < self.cols, "column {} out of {}", j, self.cols);
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(|element| element.to_string()).fmt(f)
+    }
+}
+
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_HIGHLIGHT: &str = "\x1b[1;31m";
+
+/// The 256 colour palette entry a cell's background is shaded with.
+type Shade<'a, T> = Box<dyn Fn(&T) -> u8 + 'a>;
+
+/// A [`Matrix`] as [`Matrix::render`] formats it.
+pub struct Render<'a, T, F> {
+    matrix: &'a Matrix<T>,
+    cell: F,
+    rulers: bool,
+    highlighted: HashSet<Index>,
+    heat: Option<Shade<'a, T>>,
+}
+
+impl<'a, T, F: Fn(&T) -> String> Render<'a, T, F> {
+    /// Numbers the rows on the left, and the columns above.
+    pub fn rulers(mut self) -> Self {
+        self.rulers = true;
+        self
+    }
+
+    /// Shows the `cells` in bold red, using ANSI escape codes.
+    pub fn highlight(mut self, cells: impl IntoIterator<Item = Index>) -> Self {
+        self.highlighted.extend(cells);
+        self
+    }
+
+    /// Shades the background of each cell from black for the smallest element to white for the
+    /// largest, using ANSI escape codes.
+    pub fn heatmap(mut self) -> Self
+    where
+        T: ToPrimitive,
+    {
+        let values = self.matrix.data.iter().filter_map(|e| e.to_f64());
+        let min = values.clone().fold(f64::INFINITY, f64::min);
+        let max = values.fold(f64::NEG_INFINITY, f64::max);
+        let spread = if max > min { max - min } else { 1.0 };
+
+        // 232 to 255 are the greys of the 256 colour palette
+        self.heat = Some(Box::new(move |e| {
+            let shade = e.to_f64().map_or(0.0, |v| (v - min) / spread);
+            232 + (shade * 23.0).round() as u8
+        }));
+        self
+    }
+
+    fn write_column_ruler(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        margin: usize,
+        width: usize,
+        digit: impl Fn(usize) -> Option<usize>,
+    ) -> fmt::Result {
+        write!(f, "{:margin$}", "")?;
+        for j in 0..self.matrix.cols {
+            let separator = if width > 1 && j > 0 { " " } else { "" };
+            match digit(j) {
+                Some(digit) => write!(f, "{}{:>width$}", separator, digit)?,
+                None => write!(f, "{}{:width$}", separator, "")?,
+            }
+        }
+        writeln!(f)
+    }
+}
+
+impl<T, F: Fn(&T) -> String> fmt::Display for Render<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells = self.matrix.data.iter().map(&self.cell).collect::<Vec<_>>();
+        let width = cells.iter().map(|c| c.chars().count()).max().unwrap_or(0);
+        let label_width = self.matrix.rows.saturating_sub(1).to_string().len();
+        let margin = if self.rulers { label_width + 1 } else { 0 };
+
+        if self.rulers {
+            if self.matrix.cols > 10 {
+                self.write_column_ruler(f, margin, width, |j| {
+                    j.is_multiple_of(10).then_some(j / 10 % 10)
+                })?;
+            }
+            self.write_column_ruler(f, margin, width, |j| Some(j % 10))?;
+        }
+
+        for i in 0..self.matrix.rows {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            if self.rulers {
+                write!(f, "{:>label_width$} ", i)?;
+            }
+            for j in 0..self.matrix.cols {
+                if width > 1 && j > 0 {
+                    write!(f, " ")?;
+                }
+                let element = &self.matrix[(i, j)];
+                let styled = self.highlighted.contains(&(i, j)) || self.heat.is_some();
+                if self.highlighted.contains(&(i, j)) {
+                    write!(f, "{}", ANSI_HIGHLIGHT)?;
+                }
+                if let Some(heat) = &self.heat {
+                    write!(f, "\x1b[48;5;{}m", heat(element))?;
+                }
+                write!(f, "{:>width$}", cells[i * self.matrix.cols + j])?;
+                if styled {
+                    write!(f, "{}", ANSI_RESET)?;
+                }
+            }
+        }
+        Ok(())
     }
 }
 
 pub struct ElementsInDirection<'a, T> {
-    data: &'a Matrix<T>,
+    data: MatrixView<'a, T>,
     // start_index: Index,
     current_index: Option<InnerIndex>,
     direction: Direction,
@@ -193,3 +823,268 @@ impl<'a, T> Iterator for ElementsInDirection<'a, T> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    /// A matrix whose elements are their own position in `as_slice`.
+    fn numbered(rows: usize, cols: usize) -> Matrix<usize> {
+        Matrix::try_from(
+            (0..rows)
+                .map(|i| (0..cols).map(|j| i * cols + j).collect())
+                .collect::<Vec<Vec<_>>>(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn rows_are_stored_one_after_the_other() {
+        let matrix = Matrix::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+
+        assert_eq!(&[1, 2, 3, 4, 5, 6], matrix.as_slice());
+        assert_eq!(&[4, 5, 6], matrix.row(1));
+        assert_eq!(
+            vec![&[1, 2, 3], &[4, 5, 6]],
+            matrix.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(6, matrix[(1, 2)]);
+    }
+
+    #[test]
+    fn only_rectangular_grids_are_matrices() {
+        let error = |rows: Vec<Vec<u8>>| Matrix::try_from(rows).unwrap_err().to_string();
+
+        assert_eq!("invalid grid: there are no rows", error(vec![]));
+        assert_eq!("invalid grid: there are no columns", error(vec![vec![]]));
+        assert_eq!(
+            "invalid grid: row 1 has 1 columns instead of 2",
+            error(vec![vec![1, 2], vec![3]])
+        );
+    }
+
+    #[test]
+    fn stencils_clip_wrap_or_pad_the_edges() {
+        let matrix = numbered(3, 3);
+        let offsets = Direction::ALL.map(Direction::offset);
+        let stencil = |boundary| {
+            matrix
+                .stencil((0, 0), &offsets, boundary)
+                .copied()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec![1, 3, 4], stencil(Boundary::Clip));
+        assert_eq!(vec![2, 1, 6, 3, 8, 7, 5, 4], stencil(Boundary::Wrap));
+        assert_eq!(vec![9, 1, 9, 3, 9, 9, 9, 4], stencil(Boundary::Pad(&9)));
+        assert_eq!(8, matrix.surrounding_indices((1, 1)).count());
+
+        let empty = Matrix::<u8>::new((0, 0));
+        assert_eq!(None, empty.offset_index((0, 0), (1, 1), true));
+        assert_eq!(
+            None,
+            matrix.view(0..0, 0..3).offset_index((0, 0), (0, 1), true)
+        );
+    }
+
+    #[test]
+    fn grids_parse_from_characters() {
+        let digit = |c: char| c.to_digit(10);
+        let (matrix, markers) =
+            Matrix::from_grid_str_with_markers("12\n34\n", &['1', '4'], "a digit", digit).unwrap();
+
+        assert_eq!(&[1, 2, 3, 4], matrix.as_slice());
+        assert_eq!(vec![('1', (0, 0)), ('4', (1, 1))], markers);
+
+        let error = |input| {
+            Matrix::from_grid_str(input, "a digit", digit)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(error("").starts_with("parse error at line 1, column 1: expected a row of cells"));
+        assert!(error("12\n3x").starts_with("parse error at line 2, column 2: expected a digit"));
+        assert!(error("12\n3").starts_with("parse error at line 2, column 1: expected a row of 2"));
+    }
+
+    #[test]
+    fn matrices_render_with_rulers_and_highlights() {
+        let matrix = Matrix::try_from(vec![vec![1, 2, 3], vec![4, 5, 60]]).unwrap();
+
+        assert_eq!(" 1  2  3\n 4  5 60", matrix.to_string());
+        assert_eq!(
+            "   0  1  2\n0  1  2  3\n1  4  5 \x1b[1;31m60\x1b[0m",
+            matrix
+                .render(|e| e.to_string())
+                .rulers()
+                .highlight([(1, 2)])
+                .to_string()
+        );
+        assert_eq!(
+            "\x1b[48;5;232m.\x1b[0m\x1b[48;5;255m#\x1b[0m",
+            Matrix::try_from(vec![vec![0u8, 9]])
+                .unwrap()
+                .render(|&e| if e > 0 { "#" } else { "." }.to_string())
+                .heatmap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn regions_are_connected_elements() {
+        let land = |c: &char| *c == '#';
+        let matrix = Matrix::from_grid_str("#..#\n.#.#\n....", "a cell", Some).unwrap();
+
+        let (labels, regions) = matrix.label_components(land, Connectivity::Four);
+        assert_eq!(
+            vec![1, 2, 1],
+            regions.iter().map(Region::size).collect::<Vec<_>>()
+        );
+        assert_eq!(Some(1), labels[(1, 3)]);
+        assert_eq!(
+            ((0, 3), (1, 3)),
+            (regions[1].top_left, regions[1].bottom_right)
+        );
+
+        let (_, regions) = matrix.label_components(land, Connectivity::Eight);
+        assert_eq!(
+            vec![2, 2],
+            regions.iter().map(Region::size).collect::<Vec<_>>()
+        );
+
+        // Numbered by the first cell in reading order, not by the corner of their bounds
+        let hook = Matrix::from_grid_str("..#..#\n.....#\n######", "a cell", Some).unwrap();
+        let (_, regions) = hook.label_components(land, Connectivity::Four);
+        assert_eq!(
+            vec![(0, 2), (0, 0)],
+            regions
+                .iter()
+                .map(|region| region.top_left)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, 2), (0, 5)],
+            vec![regions[0].cells[0], regions[1].cells[0]]
+        );
+
+        let water = matrix.flood_fill((2, 0), |c| *c == '.', Connectivity::Four);
+        assert_eq!(Some(8), water.map(|region| region.size()));
+        assert_eq!(
+            None,
+            matrix.flood_fill((0, 0), |c| *c == '.', Connectivity::Four)
+        );
+    }
+
+    #[test]
+    fn matrices_turn_and_flip() {
+        let matrix = Matrix::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let rows = |matrix: Matrix<i32>| matrix.rows().map(<[_]>::to_vec).collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]],
+            rows(matrix.clone().transpose())
+        );
+        assert_eq!(
+            vec![vec![4, 1], vec![5, 2], vec![6, 3]],
+            rows(matrix.clone().rotate_cw())
+        );
+        assert_eq!(
+            vec![vec![3, 6], vec![2, 5], vec![1, 4]],
+            rows(matrix.clone().rotate_ccw())
+        );
+        assert_eq!(
+            vec![vec![3, 2, 1], vec![6, 5, 4]],
+            rows(matrix.clone().flip_horizontal())
+        );
+        assert_eq!(
+            vec![vec![4, 5, 6], vec![1, 2, 3]],
+            rows(matrix.flip_vertical())
+        );
+    }
+
+    #[test]
+    fn views_borrow_part_of_a_matrix() {
+        let matrix = numbered(4, 5);
+        let view = matrix.view(1..4, 2..5);
+
+        assert_eq!(&[7, 8, 9], view.row(0));
+        assert_eq!(19, view[(2, 2)]);
+        assert_eq!(None, view.get((0, 3)));
+        assert_eq!(
+            vec![&18, &17],
+            view.elements_to_left((2, 2)).collect::<Vec<_>>()
+        );
+        assert_eq!(3, view.surrounding_indices((0, 0)).count());
+        assert_eq!(&[17, 18], view.view(1..3, 0..2).to_matrix().row(1));
+    }
+
+    proptest! {
+        #[test]
+        fn get_is_some_exactly_inside_the_bounds(
+            rows in 1..6usize,
+            cols in 1..6usize,
+            i in 0..8usize,
+            j in 0..8usize,
+        ) {
+            let mut matrix = numbered(rows, cols);
+            let inside = i < rows && j < cols;
+            let expected = inside.then_some(i * cols + j);
+
+            prop_assert_eq!(expected, matrix.get((i, j)).copied());
+            prop_assert_eq!(expected, matrix.get_mut((i, j)).map(|e| *e));
+        }
+
+        #[test]
+        fn ragged_rows_are_rejected(lengths in prop::collection::vec(0..4usize, 0..5)) {
+            let rows = lengths.iter().map(|&len| vec![0; len]).collect::<Vec<_>>();
+            let rectangular = lengths.first().is_some_and(|&first| {
+                first > 0 && lengths.iter().all(|&len| len == first)
+            });
+
+            prop_assert_eq!(rectangular, Matrix::try_from(rows).is_ok());
+        }
+
+        #[test]
+        fn neighbours_and_directions_stay_inside(
+            rows in 1..6usize,
+            cols in 1..6usize,
+            i in 0..6usize,
+            j in 0..6usize,
+        ) {
+            prop_assume!(i < rows && j < cols);
+            let matrix = numbered(rows, cols);
+
+            for (ni, nj) in matrix.neighbouring_indices((i, j)) {
+                prop_assert!(ni < rows && nj < cols);
+                prop_assert_eq!(1, ni.abs_diff(i) + nj.abs_diff(j));
+            }
+            prop_assert_eq!(j, matrix.elements_to_left((i, j)).count());
+            prop_assert_eq!(cols - j - 1, matrix.elements_to_right((i, j)).count());
+            prop_assert_eq!(i, matrix.elements_to_top((i, j)).count());
+            prop_assert_eq!(rows - i - 1, matrix.elements_to_bottom((i, j)).count());
+
+            for (ni, nj) in matrix.surrounding_indices((i, j)) {
+                prop_assert!(ni < rows && nj < cols);
+                prop_assert_eq!(1, ni.abs_diff(i).max(nj.abs_diff(j)));
+            }
+            prop_assert_eq!(
+                i.min(j),
+                matrix.elements_in_direction((i, j), Direction::TopLeft).count()
+            );
+        }
+
+        #[test]
+        fn four_quarter_turns_are_no_turn(rows in 1..6usize, cols in 1..6usize) {
+            let matrix = numbered(rows, cols);
+            let turned = matrix.clone().rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+
+            prop_assert_eq!(&matrix, &turned);
+            prop_assert_eq!(
+                matrix.clone().rotate_cw(),
+                matrix.clone().transpose().flip_horizontal()
+            );
+            prop_assert_eq!(matrix.clone().rotate_ccw(), matrix.rotate_cw().flip_horizontal().flip_vertical());
+        }
+    }
+}
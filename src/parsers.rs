@@ -1,7 +1,143 @@
 use nom::combinator::map_res;
-use nom::{character::complete::digit1, character::complete::i32, combinator::map, IResult};
+use nom::error::{ErrorKind, FromExternalError};
+use nom::{character::complete::digit1, character::complete::i32, combinator::map};
+use nom::{Finish, InputLength};
 
-pub fn decimal(input: &str) -> nom::IResult<&str, usize> {
+use crate::{Error, Result};
+
+/// [`nom::IResult`] with a [`ParseFailure`], so that failures keep track of what was expected
+/// where.
+pub type IResult<I, O> = nom::IResult<I, O, ParseFailure<I>>;
+
+/// A nom error that records what the parser expected at the furthest position it got to, and
+/// the [`context`]s it was in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFailure<I> {
+    input: I,
+    expected: Vec<String>,
+    context: Vec<String>,
+}
+
+impl<I> ParseFailure<I> {
+    fn expecting(input: I, expected: impl Into<String>) -> Self {
+        ParseFailure {
+            input,
+            expected: vec![expected.into()],
+            context: vec![],
+        }
+    }
+
+    /// Everything that would have been accepted, as a single phrase.
+    pub fn expected(&self) -> String {
+        match &self.expected[..] {
+            [] => "something else".to_string(),
+            [expected] => expected.clone(),
+            [init @ .., last] => format!("one of {} or {}", init.join(", "), last),
+        }
+    }
+}
+
+impl<I: InputLength> nom::error::ParseError<I> for ParseFailure<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        ParseFailure::expecting(input, describe(kind))
+    }
+
+    fn append(_input: I, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        ParseFailure::expecting(input, format!("{:?}", c))
+    }
+
+    /// Keeps whichever branch of an `alt` got further, or what both expected if they failed at
+    /// the same place.
+    fn or(mut self, other: Self) -> Self {
+        match self.input.input_len().cmp(&other.input.input_len()) {
+            std::cmp::Ordering::Less => self,
+            std::cmp::Ordering::Greater => other,
+            std::cmp::Ordering::Equal => {
+                for expected in other.expected {
+                    if !self.expected.contains(&expected) {
+                        self.expected.push(expected);
+                    }
+                }
+                self
+            }
+        }
+    }
+}
+
+impl<I: InputLength, E> FromExternalError<I, E> for ParseFailure<I> {
+    fn from_external_error(input: I, kind: ErrorKind, _e: E) -> Self {
+        ParseFailure::expecting(input, describe(kind))
+    }
+}
+
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit | ErrorKind::MapRes => "a number".to_string(),
+        ErrorKind::AlphaNumeric => "a name".to_string(),
+        ErrorKind::Eof => "end of input".to_string(),
+        kind => kind.description().to_lowercase(),
+    }
+}
+
+/// Labels everything `parser` does, so a failure inside it reports e.g.
+/// `in monkey note 3 → Operation`.
+pub fn context<'a, O>(
+    label: impl Into<String>,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    let label = label.into();
+    move |input| {
+        parser(input).map_err(|e| {
+            e.map(|mut failure| {
+                failure.context.insert(0, label.clone());
+                failure
+            })
+        })
+    }
+}
+
+/// `nom`'s `tag`, but a failure names the missing text.
+pub fn tag<'a>(expected: &'static str) -> impl Fn(&'a str) -> IResult<&'a str, &'a str> {
+    move |input: &'a str| match input.strip_prefix(expected) {
+        Some(rest) => Ok((rest, &input[..expected.len()])),
+        None => Err(nom::Err::Error(ParseFailure::expecting(
+            input,
+            format!("{:?}", expected),
+        ))),
+    }
+}
+
+/// Like `separated_list1`, except that a separator has to be followed by another element. A
+/// malformed element is reported as such, instead of quietly ending the list and leaving the rest
+/// of the input unparsed. A separator at the very end of the input is allowed.
+pub fn separated_all<'a, O, S>(
+    mut separator: impl FnMut(&'a str) -> IResult<&'a str, S>,
+    mut element: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    move |input| {
+        let (mut input, first) = element(input)?;
+        let mut elements = vec![first];
+
+        loop {
+            match separator(input) {
+                Err(nom::Err::Error(_)) => return Ok((input, elements)),
+                Err(e) => return Err(e),
+                Ok((rest, _)) if rest.trim().is_empty() => return Ok((input, elements)),
+                Ok((rest, _)) => {
+                    let (rest, next) = element(rest)?;
+                    elements.push(next);
+                    input = rest;
+                }
+            }
+        }
+    }
+}
+
+pub fn decimal(input: &str) -> IResult<&str, usize> {
     map_res(digit1, str::parse)(input)
 }
 
@@ -9,3 +145,81 @@ pub fn signed_decimal(input: &str) -> IResult<&str, isize> {
     // Parse a signed integer using the `signed` combinator
     map(i32, |n| n as isize)(input)
 }
+
+/// Runs `parser` over the whole of `input`. Anything but trailing whitespace left unparsed is an
+/// error, so a malformed line can't silently cut the input short.
+pub fn parse_all<'a, O>(
+    parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+    input: &'a str,
+) -> Result<O> {
+    parse_within(input, parser, input)
+}
+
+/// Runs `parser` over every line of `input`, reporting errors relative to the whole input.
+pub fn parse_lines<'a, O>(
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+    input: &'a str,
+) -> Result<Vec<O>> {
+    input
+        .lines()
+        .map(|line| parse_within(input, &mut parser, line))
+        .collect()
+}
+
+fn parse_within<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+    part: &'a str,
+) -> Result<O> {
+    match parser(part).finish() {
+        Ok((remaining, output)) if remaining.trim().is_empty() => Ok(output),
+        Ok((remaining, _)) => Err(Error::parse(input, remaining.trim_start(), "end of input")),
+        Err(failure) => {
+            Err(Error::parse(input, failure.input, failure.expected())
+                .with_context(failure.context))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::branch::alt;
+    use pretty_assertions::assert_eq;
+
+    fn direction(input: &str) -> IResult<&str, &str> {
+        alt((tag("L"), tag("R")))(input)
+    }
+
+    #[test]
+    fn alt_collects_everything_expected() {
+        let input = "L\nR\nX";
+        let error = parse_all(separated_all(tag("\n"), direction), input).unwrap_err();
+
+        assert_eq!(
+            "parse error at line 3, column 1: expected one of \"L\" or \"R\"\n  3 | X\n    | ^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn context_is_reported_outermost_first() {
+        let mut note = context("monkey note 3", context("Operation", tag("new = old")));
+        let input = "new = new";
+        let error = parse_all(&mut note, input).unwrap_err();
+
+        assert_eq!(
+            "parse error at line 1, column 1: expected \"new = old\"\n  in monkey note 3 → Operation\n  1 | new = new\n    | ^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn trailing_separator_is_allowed() {
+        let input = "L\nR\n";
+        assert_eq!(
+            vec!["L", "R"],
+            parse_all(separated_all(tag("\n"), direction), input).unwrap()
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use std::cmp::{max, min};
-use std::ops::RangeInclusive;
+use std::ops::{Range, RangeInclusive};
+
+use num::PrimInt;
 
 pub trait Subsume<T> {
     fn subsumes(&self, other: &Self) -> bool;
@@ -30,27 +32,583 @@ where
     }
 }
 
+/// Values that may come one right after the other, with nothing in between, as integers do. Ranges
+/// of them that do not overlap can still leave nothing out between them.
+pub trait Step: PartialOrd {
+    fn is_followed_by(&self, next: &Self) -> bool;
+}
+
+macro_rules! integer_step {
+    ($($t:ty),*) => {
+        $(impl Step for $t {
+            fn is_followed_by(&self, next: &Self) -> bool {
+                self.checked_add(1) == Some(*next)
+            }
+        })*
+    };
+}
+
+integer_step!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// There is always another value between two floats, so their ranges only touch if they overlap.
+macro_rules! float_step {
+    ($($t:ty),*) => {
+        $(impl Step for $t {
+            fn is_followed_by(&self, _next: &Self) -> bool {
+                false
+            }
+        })*
+    };
+}
+
+float_step!(f32, f64);
+
+/// The surrogates are not chars, so the one before them is followed by the one after them.
+impl Step for char {
+    fn is_followed_by(&self, next: &Self) -> bool {
+        match *self {
+            '\u{D7FF}' => *next == '\u{E000}',
+            c => char::from_u32(c as u32 + 1) == Some(*next),
+        }
+    }
+}
+
+/// Whether a range ending at `end` and one starting at `start` leave no value between them.
+fn touches<T: Step>(end: &T, start: &T) -> bool {
+    end >= start || end.is_followed_by(start)
+}
+
+pub trait Touch<T> {
+    /// Whether the ranges overlap, or one of them starts right after the other ends.
+    fn touches(&self, other: &Self) -> bool;
+}
+
+impl<T> Touch<T> for RangeInclusive<T>
+where
+    T: Step,
+{
+    fn touches(&self, other: &Self) -> bool {
+        touches(self.end(), other.start()) && touches(other.end(), self.start())
+    }
+}
+
 pub trait Merge<T> {
     fn merge(&self, other: &Self) -> Option<Self>
     where
         Self: Sized;
 }
 
+/// Merges ranges that touch, so `1..=3` and `4..=6` make `1..=6`.
 impl<T> Merge<T> for RangeInclusive<T>
 where
-    T: PartialOrd + Ord + Clone,
+    T: Step + Clone,
 {
     fn merge(&self, other: &Self) -> Option<Self>
     where
         Self: Sized,
     {
-        if self.overlaps(other) {
-            Some(
-                min(self.start().clone(), other.start().clone())
-                    ..=max(self.end().clone(), other.end().clone()),
-            )
+        if self.touches(other) {
+            Some(lower(self.start(), other.start())..=higher(self.end(), other.end()))
         } else {
             None
         }
     }
 }
+
+fn lower<T: PartialOrd + Clone>(a: &T, b: &T) -> T {
+    if a <= b {
+        a.clone()
+    } else {
+        b.clone()
+    }
+}
+
+fn higher<T: PartialOrd + Clone>(a: &T, b: &T) -> T {
+    if a >= b {
+        a.clone()
+    } else {
+        b.clone()
+    }
+}
+
+pub trait Intersect<T> {
+    /// The values in both ranges, unless there are none.
+    fn intersect(&self, other: &Self) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+impl<T> Intersect<T> for RangeInclusive<T>
+where
+    T: PartialOrd + Clone,
+{
+    fn intersect(&self, other: &Self) -> Option<Self> {
+        let range = higher(self.start(), other.start())..=lower(self.end(), other.end());
+        (!range.is_empty()).then_some(range)
+    }
+}
+
+impl<T> Intersect<T> for Range<T>
+where
+    T: PartialOrd + Clone,
+{
+    fn intersect(&self, other: &Self) -> Option<Self> {
+        let range = higher(&self.start, &other.start)..lower(&self.end, &other.end);
+        (!range.is_empty()).then_some(range)
+    }
+}
+
+pub trait Difference<T> {
+    /// The values in this range but not in `other`, as up to two ranges: those before `other`
+    /// and those after it.
+    fn difference(&self, other: &Self) -> Vec<Self>
+    where
+        Self: Sized;
+}
+
+impl<T> Difference<T> for RangeInclusive<T>
+where
+    T: PrimInt,
+{
+    fn difference(&self, other: &Self) -> Vec<Self> {
+        if self.intersect(other).is_none() {
+            return [self.clone()]
+                .into_iter()
+                .filter(|r| !r.is_empty())
+                .collect();
+        }
+
+        let mut pieces = Vec::new();
+        if self.start() < other.start() {
+            pieces.push(*self.start()..=*other.start() - T::one());
+        }
+        if self.end() > other.end() {
+            pieces.push(*other.end() + T::one()..=*self.end());
+        }
+        pieces
+    }
+}
+
+impl<T> Difference<T> for Range<T>
+where
+    T: PartialOrd + Clone,
+{
+    fn difference(&self, other: &Self) -> Vec<Self> {
+        if self.intersect(other).is_none() {
+            return [self.clone()]
+                .into_iter()
+                .filter(|r| !r.is_empty())
+                .collect();
+        }
+
+        [
+            self.start.clone()..other.start.clone(),
+            other.end.clone()..self.end.clone(),
+        ]
+        .into_iter()
+        .filter(|r| !r.is_empty())
+        .collect()
+    }
+}
+
+pub trait Split<T> {
+    /// The values before `at`, and those from `at` on, unless there are none.
+    fn split_at(&self, at: T) -> (Option<Self>, Option<Self>)
+    where
+        Self: Sized;
+}
+
+impl<T> Split<T> for RangeInclusive<T>
+where
+    T: PrimInt,
+{
+    fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        let before = at
+            .checked_sub(&T::one())
+            .map(|last| *self.start()..=min(*self.end(), last))
+            .filter(|r| !r.is_empty());
+        let after = Some(max(*self.start(), at)..=*self.end()).filter(|r| !r.is_empty());
+        (before, after)
+    }
+}
+
+impl<T> Split<T> for Range<T>
+where
+    T: PartialOrd + Clone,
+{
+    fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        let before = Some(self.start.clone()..lower(&self.end, &at)).filter(|r| !r.is_empty());
+        let after = Some(higher(&self.start, &at)..self.end.clone()).filter(|r| !r.is_empty());
+        (before, after)
+    }
+}
+
+/// How many integers are in a range. This is not called `len`, which would be ambiguous with
+/// [`ExactSizeIterator::len`] for the ranges that implement it.
+///
+/// The length is a `T` as well, so it has to fit in one: `i8::MIN..=i8::MAX` holds 256 values,
+/// more than an `i8` can count.
+pub trait Length<T> {
+    /// `None` if the length does not fit in a `T`.
+    fn checked_length(&self) -> Option<T>;
+
+    /// Panics if the length does not fit in a `T`, see [`Length::checked_length`].
+    fn length(&self) -> T {
+        self.checked_length()
+            .expect("the length of the range does not fit in its type")
+    }
+}
+
+impl<T> Length<T> for RangeInclusive<T>
+where
+    T: PrimInt,
+{
+    fn checked_length(&self) -> Option<T> {
+        if self.is_empty() {
+            Some(T::zero())
+        } else {
+            self.end().checked_sub(self.start())?.checked_add(&T::one())
+        }
+    }
+}
+
+impl<T> Length<T> for Range<T>
+where
+    T: PrimInt,
+{
+    fn checked_length(&self) -> Option<T> {
+        if self.is_empty() {
+            Some(T::zero())
+        } else {
+            self.end.checked_sub(&self.start)
+        }
+    }
+}
+
+/// A set of integers, kept as disjoint ranges, sorted by where they start, with no two of them
+/// next to each other.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RangeSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T: PrimInt + Step> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    pub fn ranges(&self) -> &[RangeInclusive<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many values are in the set. Panics if that does not fit in a `T`, like
+    /// [`Length::length`].
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .try_fold(T::zero(), |len, r| len.checked_add(&r.checked_length()?))
+            .expect("the size of the set does not fit in its type")
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| *r.end() < value);
+        self.ranges.get(i).is_some_and(|r| r.contains(&value))
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|r| !touches(r.end(), &start));
+        let last = self.ranges.partition_point(|r| touches(&end, r.start()));
+        if first < last {
+            start = min(start, *self.ranges[first].start());
+            end = max(end, *self.ranges[last - 1].end());
+        }
+        self.ranges.splice(first..last, [start..=end]);
+    }
+
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|r| *r.end() < start);
+        let last = self.ranges.partition_point(|r| *r.start() <= end);
+        if first == last {
+            return;
+        }
+
+        let mut pieces = Vec::new();
+        if *self.ranges[first].start() < start {
+            pieces.push(*self.ranges[first].start()..=start - T::one());
+        }
+        if *self.ranges[last - 1].end() > end {
+            pieces.push(end + T::one()..=*self.ranges[last - 1].end());
+        }
+        self.ranges.splice(first..last, pieces);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in &other.ranges {
+            difference.remove(range.clone());
+        }
+        difference
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.difference(&self.difference(other))
+    }
+
+    /// The values in `bounds` that are not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        RangeSet::from_iter([bounds]).difference(self)
+    }
+
+    /// The ranges of values between the first and the last range that are not in the set.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges
+            .windows(2)
+            .map(|pair| *pair[0].end() + T::one()..=*pair[1].start() - T::one())
+    }
+}
+
+impl<T: PrimInt + Step> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// An axis-aligned box in `N` dimensions: the points whose coordinate on each axis is in the
+/// range for that axis.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AaBox<T, const N: usize> {
+    axes: [RangeInclusive<T>; N],
+}
+
+impl<T: PrimInt, const N: usize> AaBox<T, N> {
+    pub fn new(axes: [RangeInclusive<T>; N]) -> Self {
+        AaBox { axes }
+    }
+
+    pub fn axes(&self) -> &[RangeInclusive<T>; N] {
+        &self.axes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|axis| axis.is_empty())
+    }
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(axis, x)| axis.contains(x))
+    }
+
+    /// How many points are in the box.
+    pub fn volume(&self) -> T {
+        self.axes
+            .iter()
+            .fold(T::one(), |volume, axis| volume * axis.length())
+    }
+}
+
+impl<T: PrimInt, const N: usize> Subsume<T> for AaBox<T, N> {
+    fn subsumes(&self, other: &Self) -> bool {
+        other.is_empty()
+            || self
+                .axes
+                .iter()
+                .zip(&other.axes)
+                .all(|(a, b)| a.subsumes(b))
+    }
+}
+
+impl<T: PrimInt, const N: usize> Overlap<T> for AaBox<T, N> {
+    fn overlaps(&self, other: &Self) -> bool {
+        self.intersect(other).is_some()
+    }
+}
+
+impl<T: PrimInt, const N: usize> Intersect<T> for AaBox<T, N> {
+    fn intersect(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes.clone();
+        for (axis, other) in axes.iter_mut().zip(&other.axes) {
+            *axis = axis.intersect(other)?;
+        }
+        Some(AaBox { axes })
+    }
+}
+
+/// Slices off the parts of the box before and after `other` one axis at a time, so the pieces
+/// never overlap, and there are at most two for each axis.
+impl<T: PrimInt, const N: usize> Difference<T> for AaBox<T, N> {
+    fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(common) = self.intersect(other) else {
+            return [self.clone()]
+                .into_iter()
+                .filter(|b| !b.is_empty())
+                .collect();
+        };
+
+        let mut rest = self.clone();
+        let mut pieces = Vec::new();
+        for d in 0..N {
+            for piece in rest.axes[d].difference(&other.axes[d]) {
+                let mut sliced = rest.clone();
+                sliced.axes[d] = piece;
+                pieces.push(sliced);
+            }
+            rest.axes[d] = common.axes[d].clone();
+        }
+        pieces
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    #[test]
+    fn integer_ranges_merge_when_adjacent() {
+        assert_eq!(Some(1..=6), (1..=3).merge(&(4..=6)));
+        assert_eq!(Some(1..=6), (4..=6).merge(&(1..=3)));
+        assert_eq!(None, (1..=3).merge(&(5..=6)));
+        assert_eq!(None, (u8::MAX..=u8::MAX).merge(&(0..=1)));
+        assert_eq!(Some('a'..='z'), ('a'..='m').merge(&('n'..='z')));
+        assert_eq!(None, ('a'..='m').merge(&('o'..='z')));
+        assert_eq!(
+            Some('\u{D000}'..='\u{E0FF}'),
+            ('\u{D000}'..='\u{D7FF}').merge(&('\u{E000}'..='\u{E0FF}'))
+        );
+
+        assert!((1.0..=2.0).touches(&(2.0..=3.0)));
+        assert!(!(1.0..=2.0).touches(&(2.5..=3.0)));
+        assert_eq!(Some(1.0..=3.0), (1.0..=2.0).merge(&(2.0..=3.0)));
+    }
+
+    #[test]
+    fn ranges_intersect_subtract_and_split() {
+        assert_eq!(Some(3..=5), (1..=5).intersect(&(3..=8)));
+        assert_eq!(None, (1..=2).intersect(&(3..=8)));
+        assert_eq!(Some(3..5), (1..5).intersect(&(3..8)));
+        assert_eq!(None, (1..3).intersect(&(3..8)));
+
+        assert_eq!(vec![1..=2, 6..=8], (1..=8).difference(&(3..=5)));
+        assert_eq!(vec![6..=8], (3..=8).difference(&(1..=5)));
+        assert_eq!(
+            Vec::<RangeInclusive<i32>>::new(),
+            (3..=4).difference(&(1..=5))
+        );
+        assert_eq!(vec![1..3, 5..8], (1..8).difference(&(3..5)));
+        assert_eq!(vec![1..3], (1..3).difference(&(3..5)));
+
+        assert_eq!((Some(1..=2), Some(3..=5)), (1..=5).split_at(3));
+        assert_eq!((None, Some(1..=5)), (1..=5).split_at(i32::MIN));
+        assert_eq!((Some(1..5), None), (1..5).split_at(7));
+
+        assert_eq!(5, (1..=5).length());
+        assert_eq!(4, (1..5).length());
+        assert_eq!(0, (5..5).length());
+        assert_eq!(i8::MAX, (0..=i8::MAX - 1).length());
+        assert_eq!(i8::MAX, (i8::MIN..-1).length());
+        assert_eq!(u8::MAX, (1..=u8::MAX).length());
+        assert_eq!(None, (i8::MIN..=i8::MAX).checked_length());
+        assert_eq!(None, (i8::MIN..0).checked_length());
+        assert_eq!(None, (0..=u8::MAX).checked_length());
+    }
+
+    #[test]
+    fn range_sets_keep_disjoint_ranges() {
+        let mut set = [5..=8, 1..=2, 3..=3, 7..=10, 14..=20]
+            .into_iter()
+            .collect::<RangeSet<i32>>();
+        assert_eq!(&[1..=3, 5..=10, 14..=20], set.ranges());
+        assert_eq!(vec![4..=4, 11..=13], set.gaps().collect::<Vec<_>>());
+
+        set.remove(8..=15);
+        assert_eq!(&[1..=3, 5..=7, 16..=20], set.ranges());
+        assert_eq!(11, set.len());
+        assert_eq!(
+            u8::MAX,
+            [0..=99, 101..=u8::MAX]
+                .into_iter()
+                .collect::<RangeSet<u8>>()
+                .len()
+        );
+        assert!(set.contains(6) && !set.contains(4));
+
+        let other = [0..=5, 18..=30].into_iter().collect::<RangeSet<i32>>();
+        assert_eq!(&[0..=7, 16..=30], set.union(&other).ranges());
+        assert_eq!(&[1..=3, 5..=5, 18..=20], set.intersection(&other).ranges());
+        assert_eq!(&[6..=7, 16..=17], set.difference(&other).ranges());
+        assert_eq!(&[0..=0, 4..=4, 8..=15], set.complement(0..=15).ranges());
+    }
+
+    #[test]
+    fn boxes_subtract_into_disjoint_pieces() {
+        let cube = AaBox::new([0..=2, 0..=2, 0..=2]);
+        let corner = AaBox::new([2..=3, 2..=3, 2..=3]);
+
+        assert_eq!(27, cube.volume());
+        assert_eq!(
+            Some(AaBox::new([2..=2, 2..=2, 2..=2])),
+            cube.intersect(&corner)
+        );
+        assert!(cube.overlaps(&corner) && !cube.subsumes(&corner));
+        assert!(cube.contains(&[1, 2, 0]) && !cube.contains(&[1, 3, 0]));
+        assert_eq!(
+            vec![
+                AaBox::new([0..=1, 0..=2, 0..=2]),
+                AaBox::new([2..=2, 0..=1, 0..=2]),
+                AaBox::new([2..=2, 2..=2, 0..=1]),
+            ],
+            cube.difference(&corner)
+        );
+    }
+
+    fn rectangle() -> impl Strategy<Value = AaBox<i32, 2>> {
+        (0..6, 0..4, 0..6, 0..4).prop_map(|(x, w, y, h)| AaBox::new([x..=x + w, y..=y + h]))
+    }
+
+    proptest! {
+        #[test]
+        fn differences_cover_exactly_what_is_left(a in rectangle(), b in rectangle()) {
+            let pieces = a.difference(&b);
+
+            for x in -1..12 {
+                for y in -1..12 {
+                    let expected = a.contains(&[x, y]) && !b.contains(&[x, y]);
+                    let covering = pieces.iter().filter(|p| p.contains(&[x, y])).count();
+                    prop_assert_eq!(usize::from(expected), covering);
+                }
+            }
+            let common = a.intersect(&b).map_or(0, |common| common.volume());
+            let left = pieces.iter().map(AaBox::volume).sum::<i32>();
+            prop_assert_eq!(a.volume(), common + left);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::days::{self, timed, Puzzle};
+use crate::input::InputSource;
+use crate::{Error, Result};
+
+/// The answer to one part of a day, and how long it took to solve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+enum Task {
+    Parse(usize),
+    Solve(usize, usize, Arc<dyn Puzzle>),
+}
+
+enum Outcome {
+    Parsed(usize, Result<Arc<dyn Puzzle>>),
+    Solved(usize, usize, Result<String>, Duration),
+    Panicked(Box<dyn Any + Send>),
+}
+
+fn work(task: Task, input: &InputSource) -> Outcome {
+    match task {
+        Task::Parse(day) => {
+            let solver = days::solver(day).unwrap();
+            let puzzle = input.read(day).and_then(|input| solver(&input));
+            Outcome::Parsed(day, puzzle.map(Arc::from))
+        }
+        Task::Solve(day, part, puzzle) => {
+            let (answer, elapsed) = timed(|| match part {
+                1 => puzzle.part1(),
+                _ => puzzle.part2(),
+            });
+            Outcome::Solved(day, part, answer, elapsed)
+        }
+    }
+}
+
+/// Solves `parts` of each of `days` on `jobs` threads. Every day is parsed once, after which its
+/// parts are solved independently of each other.
+///
+/// `report` is called in order of day and part, whichever order they are solved in. The first
+/// error in that order stops the run, and is returned with the day and part it happened on. An
+/// error parsing a day happens on the first of `parts`.
+pub fn solve_days(
+    days: &[usize],
+    parts: &[usize],
+    input: &InputSource,
+    jobs: usize,
+    mut report: impl FnMut(Solved),
+) -> std::result::Result<(), (usize, usize, Error)> {
+    let order = days
+        .iter()
+        .flat_map(|&day| parts.iter().map(move |&part| (day, part)))
+        .collect::<Vec<_>>();
+    let (task_sender, tasks) = mpsc::channel();
+    let tasks = Mutex::new(tasks);
+    let (outcome_sender, outcomes) = mpsc::channel();
+    let stopped = AtomicBool::new(false);
+
+    thread::scope(|scope| {
+        // Owned by this closure, so that the workers stop once it returns
+        let task_sender = task_sender;
+
+        for _ in 0..jobs.max(1) {
+            let outcome_sender = outcome_sender.clone();
+            let (tasks, stopped) = (&tasks, &stopped);
+            scope.spawn(move || loop {
+                let task = tasks.lock().unwrap().recv();
+                let Ok(task) = task else { break };
+                if stopped.load(Ordering::Relaxed) {
+                    continue;
+                }
+
+                let outcome = panic::catch_unwind(AssertUnwindSafe(|| work(task, input)))
+                    .unwrap_or_else(Outcome::Panicked);
+                if outcome_sender.send(outcome).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(outcome_sender);
+
+        let mut pending = days.len();
+        for &day in days {
+            task_sender.send(Task::Parse(day)).unwrap();
+        }
+
+        let mut finished = BTreeMap::new();
+        let mut next = 0;
+        while pending > 0 {
+            pending -= 1;
+            match outcomes.recv().unwrap() {
+                Outcome::Parsed(day, Ok(puzzle)) => {
+                    for &part in parts {
+                        task_sender
+                            .send(Task::Solve(day, part, puzzle.clone()))
+                            .unwrap();
+                        pending += 1;
+                    }
+                }
+                // Reported in place of the first part, as there will be no answers for the day
+                Outcome::Parsed(day, Err(e)) => {
+                    finished.insert((day, parts[0]), Err(e));
+                }
+                Outcome::Solved(day, part, answer, elapsed) => {
+                    let solved = answer.map(|answer| Solved {
+                        day,
+                        part,
+                        answer,
+                        elapsed,
+                    });
+                    finished.insert((day, part), solved);
+                }
+                Outcome::Panicked(payload) => {
+                    stopped.store(true, Ordering::Relaxed);
+                    panic::resume_unwind(payload);
+                }
+            }
+
+            while let Some(solved) = order.get(next).and_then(|key| finished.remove(key)) {
+                let (day, part) = order[next];
+                next += 1;
+                match solved {
+                    Ok(solved) => report(solved),
+                    Err(e) => {
+                        stopped.store(true, Ordering::Relaxed);
+                        return Err((day, part, e));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::fs;
+
+    /// A directory of inputs for `days`, named after the test so they do not overwrite each other.
+    fn inputs(test: &str, days: &[(usize, &str)]) -> InputSource {
+        let dir = std::env::temp_dir().join(format!("aoc22-runner-{}", test));
+        fs::create_dir_all(&dir).unwrap();
+        for (day, input) in days {
+            fs::write(dir.join(format!("day{:02}.txt", day)), input).unwrap();
+        }
+        InputSource::Directory(dir)
+    }
+
+    type Answers = Vec<(usize, usize, String)>;
+
+    fn run(
+        days: &[usize],
+        parts: &[usize],
+        input: &InputSource,
+        jobs: usize,
+    ) -> (Answers, std::result::Result<(), (usize, usize, Error)>) {
+        let mut answers = vec![];
+        let result = solve_days(days, parts, input, jobs, |solved| {
+            answers.push((solved.day, solved.part, solved.answer))
+        });
+        (answers, result)
+    }
+
+    const CALORIES: &str = "1000\n2000\n\n3000\n";
+    const ASSIGNMENTS: &str = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n";
+    const SIGNAL: &str = "mjqjpqmgbljsphdztnvjfqwrcgmjlb\n";
+
+    #[test]
+    fn answers_are_reported_in_order_of_day_and_part() {
+        let input = inputs("order", &[(1, CALORIES), (4, ASSIGNMENTS), (6, SIGNAL)]);
+        let expected = vec![
+            (1, 1, "3000".to_string()),
+            (1, 2, "6000".to_string()),
+            (4, 1, "2".to_string()),
+            (4, 2, "4".to_string()),
+            (6, 1, "7".to_string()),
+            (6, 2, "19".to_string()),
+        ];
+        for jobs in [1, 4] {
+            let (answers, result) = run(&[1, 4, 6], &[1, 2], &input, jobs);
+            assert!(result.is_ok());
+            assert_eq!(expected, answers);
+        }
+
+        let (answers, result) = run(&[1, 4, 6], &[2], &input, 4);
+        assert!(result.is_ok());
+        assert_eq!(
+            vec![
+                (1, 2, "6000".to_string()),
+                (4, 2, "4".to_string()),
+                (6, 2, "19".to_string()),
+            ],
+            answers
+        );
+    }
+
+    #[test]
+    fn a_parse_error_stops_the_run_after_the_days_before_it() {
+        let input = inputs("error", &[(1, CALORIES), (4, "2-4,6-x\n"), (6, SIGNAL)]);
+
+        let (answers, result) = run(&[1, 4, 6], &[1, 2], &input, 4);
+        assert_eq!(
+            vec![(1, 1, "3000".to_string()), (1, 2, "6000".to_string())],
+            answers
+        );
+        assert!(matches!(result, Err((4, 1, Error::Parse { line: 1, .. }))));
+
+        let (answers, result) = run(&[1, 4, 6], &[2], &input, 4);
+        assert_eq!(vec![(1, 2, "6000".to_string())], answers);
+        assert!(matches!(result, Err((4, 2, Error::Parse { .. }))));
+    }
+}
@@ -0,0 +1,205 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use num::Zero;
+
+/// The nodes from a source to a goal, both included, and what it cost to get there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+/// Every node reached so far, with the one it was reached from and the cost of getting to it.
+struct Reached<N, C> {
+    indices: HashMap<N, usize>,
+    nodes: Vec<(N, Option<usize>, C)>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Reached<N, C> {
+    fn new() -> Self {
+        Reached {
+            indices: HashMap::new(),
+            nodes: Vec::new(),
+        }
+    }
+
+    /// Records `node` unless it was already reached at `cost` or less, returning its index if it
+    /// was recorded.
+    fn reach(&mut self, node: N, parent: Option<usize>, cost: C) -> Option<usize>
+    where
+        C: Ord,
+    {
+        match self.indices.get(&node) {
+            Some(&i) if self.nodes[i].2 <= cost => None,
+            Some(&i) => {
+                self.nodes[i] = (node, parent, cost);
+                Some(i)
+            }
+            None => {
+                self.indices.insert(node.clone(), self.nodes.len());
+                self.nodes.push((node, parent, cost));
+                Some(self.nodes.len() - 1)
+            }
+        }
+    }
+
+    fn path(&self, mut i: usize) -> Path<N, C> {
+        let cost = self.nodes[i].2;
+        let mut nodes = vec![self.nodes[i].0.clone()];
+        while let Some(parent) = self.nodes[i].1 {
+            nodes.push(self.nodes[parent].0.clone());
+            i = parent;
+        }
+        nodes.reverse();
+
+        Path { nodes, cost }
+    }
+}
+
+/// The path with the fewest steps from any of `sources` to a node `is_goal` accepts, if there is
+/// one. The cost of the path is its number of steps.
+pub fn bfs<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut reached = Reached::new();
+    let mut queue = sources
+        .into_iter()
+        .filter_map(|source| reached.reach(source, None, 0))
+        .collect::<VecDeque<_>>();
+
+    while let Some(i) = queue.pop_front() {
+        let (node, _, steps) = reached.nodes[i].clone();
+        if is_goal(&node) {
+            return Some(reached.path(i));
+        }
+
+        for successor in successors(&node) {
+            queue.extend(reached.reach(successor, Some(i), steps + 1));
+        }
+    }
+    None
+}
+
+/// The fewest steps from the nearest of `sources` to every node that can be reached from them.
+pub fn distances<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut reached = Reached::new();
+    let mut queue = sources
+        .into_iter()
+        .filter_map(|source| reached.reach(source, None, 0))
+        .collect::<VecDeque<_>>();
+
+    while let Some(i) = queue.pop_front() {
+        let (node, _, steps) = reached.nodes[i].clone();
+        for successor in successors(&node) {
+            queue.extend(reached.reach(successor, Some(i), steps + 1));
+        }
+    }
+
+    reached
+        .nodes
+        .into_iter()
+        .map(|(node, _, steps)| (node, steps))
+        .collect()
+}
+
+/// The cheapest path from any of `sources` to a node `is_goal` accepts, if there is one.
+/// `successors` gives the nodes next to a node, with the cost of moving to each of them.
+pub fn dijkstra<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(sources, successors, |_| C::zero(), is_goal)
+}
+
+/// Like [`dijkstra`], looking at the nodes `heuristic` estimates to be closest to a goal first.
+/// The path is only guaranteed to be the cheapest if `heuristic` never overestimates.
+pub fn astar<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut reached = Reached::new();
+    let mut open = BinaryHeap::new();
+    for source in sources {
+        let estimate = heuristic(&source);
+        if let Some(i) = reached.reach(source, None, C::zero()) {
+            open.push(Reverse((estimate, C::zero(), i)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, i))) = open.pop() {
+        let node = reached.nodes[i].0.clone();
+        // Left behind when a cheaper way to the node was found
+        if cost > reached.nodes[i].2 {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(reached.path(i));
+        }
+
+        for (successor, step) in successors(&node) {
+            let cost = cost + step;
+            let estimate = cost + heuristic(&successor);
+            if let Some(j) = reached.reach(successor, Some(i), cost) {
+                open.push(Reverse((estimate, cost, j)));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Steps along a line of numbers, where going up costs twice as much as going down.
+    fn line(n: &i32) -> [(i32, u32); 2] {
+        [(n + 1, 2), (n - 1, 1)]
+    }
+
+    #[test]
+    fn searches_find_the_cheapest_path() {
+        let path = bfs([0], |n| [n + 1, n - 1], |&n| n == 3).unwrap();
+        assert_eq!((vec![0, 1, 2, 3], 3), (path.nodes, path.cost));
+
+        let path = dijkstra([0, 5], line, |&n| n == 3).unwrap();
+        assert_eq!((vec![5, 4, 3], 2), (path.nodes, path.cost));
+
+        let path = astar([0], line, |&n| (3 - n).unsigned_abs(), |&n| n == 3).unwrap();
+        assert_eq!((vec![0, 1, 2, 3], 6), (path.nodes, path.cost));
+
+        assert_eq!(None, bfs([0], |&n| (n < 5).then_some(n + 1), |&n| n < 0));
+        assert_eq!(
+            HashMap::from([(0, 0), (1, 1), (2, 2)]),
+            distances([0], |&n| (n < 2).then_some(n + 1))
+        );
+    }
+}
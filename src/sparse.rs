@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use crate::matrix::{Connectivity, Matrix};
+
+/// A location in a [`SparseGrid`], as `(row, col)` like a [`Matrix`] index, but signed.
+pub type Point = (isize, isize);
+
+/// An unbounded grid that only stores the cells that are set, keeping track of the smallest
+/// rectangle around them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets every element `keep` accepts, with the top left of `matrix` at `offset`.
+    pub fn from_matrix(matrix: Matrix<T>, offset: Point, keep: impl Fn(&T) -> bool) -> Self {
+        let cols = matrix.number_of_cols();
+        let mut grid = SparseGrid::new();
+        for (k, element) in matrix.into_vec().into_iter().enumerate() {
+            if keep(&element) {
+                let (i, j) = (k / cols, k % cols);
+                grid.insert((offset.0 + i as isize, offset.1 + j as isize), element);
+            }
+        }
+        grid
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle around every cell, both
+    /// included, unless there are no cells.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Sets the cell at `point`, returning what was there before.
+    pub fn insert(&mut self, (i, j): Point, value: T) -> Option<T> {
+        self.bounds = Some(extend(self.bounds, (i, j)));
+        self.cells.insert((i, j), value)
+    }
+
+    /// Clears the cell at `point`, returning what was there.
+    pub fn remove(&mut self, (i, j): Point) -> Option<T> {
+        let removed = self.cells.remove(&(i, j))?;
+        // Only a cell on the edge can make the rectangle around them smaller
+        if let Some(((top, left), (bottom, right))) = self.bounds {
+            if i == top || i == bottom || j == left || j == right {
+                self.bounds = self
+                    .cells
+                    .keys()
+                    .fold(None, |bounds, &point| Some(extend(bounds, point)));
+            }
+        }
+        Some(removed)
+    }
+
+    /// Every cell that is set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// The cells next to `point` that are set.
+    pub fn neighbours(
+        &self,
+        (i, j): Point,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (Point, &T)> + '_ {
+        connectivity.directions().iter().filter_map(move |d| {
+            let (di, dj) = d.offset();
+            let point = (i + di, j + dj);
+            self.get(point).map(|value| (point, value))
+        })
+    }
+
+    /// The cells within the bounds as a matrix, with `fill` in place of those that are not set,
+    /// and the point its top left is at.
+    pub fn to_matrix(&self, fill: T) -> Option<(Matrix<T>, Point)>
+    where
+        T: Clone,
+    {
+        let ((top, left), (bottom, right)) = self.bounds?;
+        let rows = (top..=bottom)
+            .map(|i| {
+                (left..=right)
+                    .map(|j| self.get((i, j)).unwrap_or(&fill).clone())
+                    .collect()
+            })
+            .collect::<Vec<Vec<T>>>();
+        let matrix = Matrix::try_from(rows).expect("the bounds are never empty");
+        Some((matrix, (top, left)))
+    }
+
+    /// Draws the cells within the bounds, a row on each line, with `cell` drawing each of them
+    /// whether it is set or not.
+    pub fn render(&self, cell: impl Fn(Point, Option<&T>) -> char) -> String {
+        match self.bounds {
+            Some((top_left, bottom_right)) => self.render_area(top_left, bottom_right, cell),
+            None => String::new(),
+        }
+    }
+
+    /// Like [`SparseGrid::render`], from `top_left` to `bottom_right` instead of the bounds.
+    pub fn render_area(
+        &self,
+        (top, left): Point,
+        (bottom, right): Point,
+        cell: impl Fn(Point, Option<&T>) -> char,
+    ) -> String {
+        (top..=bottom)
+            .map(|i| {
+                (left..=right)
+                    .map(|j| cell((i, j), self.get((i, j))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn extend(bounds: Option<(Point, Point)>, (i, j): Point) -> (Point, Point) {
+    match bounds {
+        None => ((i, j), (i, j)),
+        Some(((top, left), (bottom, right))) => {
+            ((top.min(i), left.min(j)), (bottom.max(i), right.max(j)))
+        }
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn bounds_follow_the_cells() {
+        let mut grid = [((-1, 2), 'a'), ((3, -4), 'b'), ((0, 0), 'c')]
+            .into_iter()
+            .collect::<SparseGrid<_>>();
+        assert_eq!(Some(((-1, -4), (3, 2))), grid.bounds());
+
+        assert_eq!(Some('b'), grid.remove((3, -4)));
+        assert_eq!(Some(((-1, 0), (0, 2))), grid.bounds());
+        assert_eq!(
+            vec![((0, 0), &'c'), ((-1, 2), &'a')],
+            grid.neighbours((0, 1), Connectivity::Eight)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            "..a\nc..",
+            grid.render(|_, cell| cell.copied().unwrap_or('.'))
+        );
+
+        let (matrix, offset) = grid.to_matrix(' ').unwrap();
+        assert_eq!(((-1, 0), &['c', ' ', ' '][..]), (offset, matrix.row(1)));
+        assert_eq!(grid, SparseGrid::from_matrix(matrix, offset, |c| *c != ' '));
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+
+use aoc22::days;
+use aoc22::days::day05::{perform_commands, Command, CrateMover, Ship};
+use aoc22::days::day09::{Direction, Rope};
+use aoc22::days::day12::parse_hill;
+use aoc22::days::day13::Element;
+use aoc22::days::day14::{parse_rocks, Reserviour, SimulationResult};
+use aoc22::days::day15::{
+    covered_in_row, distress_beacon, parse_pairs, tuning_frequency, tuning_frequency_by_rows,
+};
+use aoc22::range::AaBox;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn crate_movers_move_crates_differently() {
+    let stacks = vec![
+        vec!["Z".to_string(), "N".to_string()],
+        vec!["M".to_string(), "C".to_string(), "D".to_string()],
+        vec!["P".to_string()],
+    ];
+    let commands = [
+        "move 1 from 2 to 1",
+        "move 3 from 1 to 3",
+        "move 2 from 2 to 1",
+        "move 1 from 1 to 2",
+    ]
+    .map(|command| command.parse::<Command>().unwrap());
+
+    let mut ship = Ship::new(stacks.clone(), CrateMover::CM9000);
+    assert_eq!("CMZ", perform_commands(&mut ship, &commands).unwrap());
+
+    let mut ship = Ship::new(stacks, CrateMover::CM9001);
+    assert_eq!("MCD", perform_commands(&mut ship, &commands).unwrap());
+}
+
+#[test]
+fn rope_tail_follows_the_head() {
+    let mut rope = Rope::new(2);
+    for _ in 0..3 {
+        rope.move_head(Direction::Right);
+    }
+
+    assert_eq!(&[(3, 0), (2, 0)], rope.knots());
+}
+
+#[test]
+fn crt_draws_a_partial_last_row() {
+    let run = days::run(days::solver(10).unwrap(), "noop\naddx 3\naddx -5").unwrap();
+
+    assert_eq!("#####.", run.part2);
+}
+
+#[test]
+fn hill_search_finds_the_fewest_steps() {
+    let hill = parse_hill("Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi").unwrap();
+
+    assert_eq!(Some(31), hill.search(hill.start(), hill.end()));
+    assert_eq!(6, hill.lowest_points().count());
+
+    let nearest = hill.nearest_lowest_point().unwrap();
+    assert_eq!((29, Some(&(4, 0))), (nearest.cost, nearest.nodes.first()));
+    assert_eq!(Some(&hill.end()), nearest.nodes.last());
+
+    let distances = hill.distances_to(hill.end());
+    assert_eq!(Some(31), distances[hill.start()]);
+    assert_eq!(Some(0), distances[hill.end()]);
+}
+
+#[test]
+fn packets_compare_in_the_right_order() {
+    let left = "[[1],[2,3,4]]".parse::<Element>().unwrap();
+    let right = "[[1],4]".parse::<Element>().unwrap();
+
+    assert!(left < right);
+}
+
+#[test]
+fn sand_settles_on_the_rocks() {
+    let rocks = parse_rocks("498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9").unwrap();
+    let mut reserviour = Reserviour::new(rocks.clone(), (500, 0)).unwrap();
+    assert_eq!(SimulationResult::IntoTheAbyss(24), reserviour.simulate());
+
+    assert_eq!(
+        "\
+.......+....
+............
+.......o....
+......ooo...
+.....#ooo##.
+....o#ooo#..
+...###ooo#..
+.....oooo#..
+..o.ooooo#..
+.#########..",
+        reserviour.to_string()
+    );
+
+    let mut reserviour = Reserviour::new_with_base(rocks, (500, 0)).unwrap();
+    assert_eq!(SimulationResult::FilledToBrim(93), reserviour.simulate());
+
+    assert!(Reserviour::new(HashSet::new(), (500, 0)).is_err());
+}
+
+#[test]
+fn sensors_cover_ranges_of_a_row() {
+    let pairs = parse_pairs(
+        "\
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=20, y=1: closest beacon is at x=15, y=3",
+    )
+    .unwrap();
+    let covered = covered_in_row(&pairs, 10);
+
+    assert_eq!(&[2..=14], covered.ranges());
+    assert_eq!(
+        &[-2..=0, 16..=20],
+        covered_in_row(&pairs, 9).complement(-2..=20).ranges()
+    );
+}
+
+#[test]
+fn both_solvers_find_the_distress_beacon() {
+    let pairs = parse_pairs(
+        "\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3",
+    )
+    .unwrap();
+    let region = AaBox::new([0..=20, 0..=20]);
+
+    // Found where the diamonds' edges cross, without falling back to scanning the rows
+    assert_eq!(Some((14, 11)), distress_beacon(&pairs, &region));
+    assert_eq!(56000011, tuning_frequency(&pairs, &region).unwrap());
+    assert_eq!(56000011, tuning_frequency_by_rows(&pairs, &region).unwrap());
+}